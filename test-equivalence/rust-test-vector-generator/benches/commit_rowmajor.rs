@@ -0,0 +1,37 @@
+//! Benchmarks a 256-column 2^12 commit, column-major through MerkleProver::commit against
+//! row-major through commit_rowmajor.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+use stwo_prover::core::backend::CpuBackend;
+use stwo_prover::core::fields::m31::BaseField;
+use stwo_prover::core::vcs::blake2_merkle::Blake2sMerkleHasher;
+use stwo_prover::core::vcs::prover::MerkleProver;
+
+#[path = "../src/merkle_ops.rs"]
+mod merkle_ops;
+
+use merkle_ops::commit_rowmajor;
+
+const N_COLUMNS: usize = 256;
+const LOG_N_ROWS: u32 = 12;
+
+fn commit_benches(c: &mut Criterion) {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let n_rows = 1usize << LOG_N_ROWS;
+    let columns: Vec<Vec<BaseField>> = (0..N_COLUMNS).map(|_| (0..n_rows).map(|_| rng.gen()).collect()).collect();
+    let rowmajor: Vec<BaseField> = (0..n_rows).flat_map(|r| columns.iter().map(move |column| column[r])).collect();
+
+    let mut group = c.benchmark_group("commit");
+    group.bench_function(format!("column-major {N_COLUMNS}x2^{LOG_N_ROWS}"), |b| {
+        b.iter(|| MerkleProver::<CpuBackend, Blake2sMerkleHasher>::commit(columns.iter().collect()).root())
+    });
+    group.bench_function(format!("row-major {N_COLUMNS}x2^{LOG_N_ROWS}"), |b| {
+        b.iter(|| commit_rowmajor::<Blake2sMerkleHasher>(&rowmajor, N_COLUMNS))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, commit_benches);
+criterion_main!(benches);
@@ -0,0 +1,48 @@
+//! Benchmarks secure_column_mul_add_assign, scalar and SIMD, on a 2^18 column.
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+use stwo_prover::core::backend::simd::SimdBackend;
+use stwo_prover::core::backend::CpuBackend;
+use stwo_prover::core::fields::qm31::SecureField;
+use stwo_prover::core::fields::secure_column::SecureColumnByCoords;
+
+#[path = "../src/secure_column_ops.rs"]
+mod secure_column_ops;
+
+use secure_column_ops::{secure_column_mul_add_assign, secure_column_mul_add_assign_simd};
+
+const LOG_LEN: u32 = 18;
+
+fn mul_add_assign_benches(c: &mut Criterion) {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let coeff = rng.gen::<SecureField>();
+    let acc: Vec<SecureField> = (0..1 << LOG_LEN).map(|_| rng.gen()).collect();
+    let other: Vec<SecureField> = (0..1 << LOG_LEN).map(|_| rng.gen()).collect();
+
+    let mut group = c.benchmark_group("mul_add_assign");
+    let cpu_acc: SecureColumnByCoords<CpuBackend> = acc.iter().cloned().collect();
+    let cpu_other: SecureColumnByCoords<CpuBackend> = other.iter().cloned().collect();
+    group.bench_function(format!("cpu 2^{LOG_LEN}"), |b| {
+        b.iter_batched_ref(
+            || cpu_acc.clone(),
+            |acc| secure_column_mul_add_assign(acc, coeff, &cpu_other),
+            BatchSize::LargeInput,
+        )
+    });
+
+    let simd_acc: SecureColumnByCoords<SimdBackend> = acc.into_iter().collect();
+    let simd_other: SecureColumnByCoords<SimdBackend> = other.into_iter().collect();
+    group.bench_function(format!("simd 2^{LOG_LEN}"), |b| {
+        b.iter_batched_ref(
+            || simd_acc.clone(),
+            |acc| secure_column_mul_add_assign_simd(acc, coeff, &simd_other),
+            BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(benches, mul_add_assign_benches);
+criterion_main!(benches);
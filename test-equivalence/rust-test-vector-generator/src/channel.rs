@@ -0,0 +1,1511 @@
+//! Channel test vectors.
+//!
+//! The two channels absorb a `u64` in different word orders, which is easy to get wrong in a port:
+//! - `Blake2sChannel::mix_u64` mixes `[low, high]` (little-endian u32 words).
+//! - `Poseidon252Channel::mix_u64` mixes `[0, 0, 0, 0, 0, high, low]`, i.e. the value as a
+//!   big-endian felt252 made of seven u32 words.
+//!
+//! The vectors below pin the exact word sequence each channel derives so ports can match them
+//! byte-for-byte.
+//!
+//! `draw_random_bytes` lengths differ too: Blake2s returns the full 32-byte hash, while
+//! Poseidon252 returns 31 bytes, the low 31 little-endian bytes of a drawn felt252 (a felt252 does
+//! not fill 32 bytes). Code should size draws with [`BLAKE2S_RANDOM_BYTES_LEN`] and
+//! [`POSEIDON252_RANDOM_BYTES_LEN`] rather than assume `BYTES_PER_HASH`.
+
+use std::collections::HashMap;
+use std::simd::u32x16;
+use num_traits::One;
+use starknet_crypto::poseidon_hash_many;
+use starknet_ff::FieldElement as FieldElement252;
+use stwo_prover::core::backend::simd::blake2s::compress16;
+use stwo_prover::core::backend::simd::m31::N_LANES;
+use stwo_prover::core::backend::simd::SimdBackend;
+use stwo_prover::core::channel::logging_channel::LoggingChannel;
+use stwo_prover::core::channel::{Blake2sChannel, Channel, MerkleChannel, Poseidon252Channel};
+use stwo_prover::core::circle::{CirclePoint, SECURE_FIELD_CIRCLE_GEN};
+use stwo_prover::core::fields::qm31::SecureField;
+use stwo_prover::core::proof_of_work::GrindOps;
+use stwo_prover::core::queries::Queries;
+use stwo_prover::core::vcs::blake2_hash::{Blake2sHash, Blake2sHasher};
+use stwo_prover::core::vcs::ops::MerkleHasher;
+
+use crate::keccak256::{Keccak256Channel, Keccak256Hash, Keccak256MerkleChannel, Keccak256MerkleHasher, KECCAK256_BYTES_PER_HASH};
+use crate::sha256::{Sha256Channel, SHA256_BYTES_PER_HASH};
+use crate::{catch_unwind_silent, qm31_to_json, TestVector};
+
+// Length of Blake2sChannel::draw_random_bytes output.
+const BLAKE2S_RANDOM_BYTES_LEN: usize = 32;
+
+// Length of Poseidon252Channel::draw_random_bytes output.
+const POSEIDON252_RANDOM_BYTES_LEN: usize = 31;
+
+// The u32 words Blake2sChannel::mix_u64 absorbs: low word first.
+fn blake2s_mix_u64_words(value: u64) -> Vec<u32> {
+    vec![value as u32, (value >> 32) as u32]
+}
+
+// The u32 words Poseidon252Channel::mix_u64 absorbs: zero padding, then high word, then low word.
+fn poseidon252_mix_u64_words(value: u64) -> Vec<u32> {
+    vec![0, 0, 0, 0, 0, (value >> 32) as u32, value as u32]
+}
+
+// Absorbs a secure-field circle point (e.g. an OOD sample point) as the felts [x, y].
+fn mix_circle_point(channel: &mut impl Channel, point: CirclePoint<SecureField>) {
+    channel.mix_felts(&[point.x, point.y]);
+}
+
+// Whether a secure-field point is a point of the base circle, i.e. could lie in an evaluation
+// domain: both coordinates have zero extension parts.
+fn is_base_circle_point(point: &CirclePoint<SecureField>) -> bool {
+    point.x.to_m31_array()[1..].iter().chain(&point.y.to_m31_array()[1..]).all(|limb| limb.0 == 0)
+}
+
+// Samples an out-of-domain point: draws a point with CirclePoint::get_random_point (the rational
+// parametrization of a drawn t) and redraws in the negligible case it lands on the base circle,
+// where every evaluation domain lives.
+fn sample_ood(channel: &mut impl Channel) -> CirclePoint<SecureField> {
+    loop {
+        let point = CirclePoint::<SecureField>::get_random_point(channel);
+        if !is_base_circle_point(&point) {
+            return point;
+        }
+    }
+}
+
+// Wraps a channel and counts the bytes absorbed by the mix_* calls: 4 per u32, 8 per u64 and 16
+// per felt. Draws and outputs are forwarded unchanged, so the transcript is identical to the
+// inner channel's. ChannelTime is defined in stwo, so the count lives in this wrapper.
+#[derive(Clone, Debug, Default)]
+struct CountingChannel<C: Channel> {
+    channel: C,
+    n_bytes_mixed: usize,
+}
+
+impl<C: Channel> CountingChannel<C> {
+    fn n_bytes_mixed(&self) -> usize {
+        self.n_bytes_mixed
+    }
+}
+
+impl<C: Channel> Channel for CountingChannel<C> {
+    const BYTES_PER_HASH: usize = C::BYTES_PER_HASH;
+
+    fn trailing_zeros(&self) -> u32 {
+        self.channel.trailing_zeros()
+    }
+
+    fn mix_u32s(&mut self, data: &[u32]) {
+        self.n_bytes_mixed += data.len() * 4;
+        self.channel.mix_u32s(data);
+    }
+
+    fn mix_felts(&mut self, felts: &[SecureField]) {
+        self.n_bytes_mixed += felts.len() * 16;
+        self.channel.mix_felts(felts);
+    }
+
+    fn mix_u64(&mut self, value: u64) {
+        self.n_bytes_mixed += 8;
+        self.channel.mix_u64(value);
+    }
+
+    fn draw_felt(&mut self) -> SecureField {
+        self.channel.draw_felt()
+    }
+
+    fn draw_felts(&mut self, n_felts: usize) -> Vec<SecureField> {
+        self.channel.draw_felts(n_felts)
+    }
+
+    fn draw_random_bytes(&mut self) -> Vec<u8> {
+        self.channel.draw_random_bytes()
+    }
+}
+
+// One channel call as seen by RecordingChannel: mixes carry their inputs, draws their outputs.
+#[derive(Clone, Debug, PartialEq)]
+enum TranscriptEvent {
+    MixU32s(Vec<u32>),
+    MixFelts(Vec<SecureField>),
+    MixU64(u64),
+    DrawFelt(SecureField),
+    DrawFelts(Vec<SecureField>),
+    DrawRandomBytes(Vec<u8>),
+}
+
+// Wraps a channel and records every call, in order, as a structured transcript, e.g. to diff a
+// run against another implementation. Calls are forwarded unchanged, like CountingChannel.
+#[derive(Clone, Debug, Default)]
+struct RecordingChannel<C: Channel> {
+    channel: C,
+    events: Vec<TranscriptEvent>,
+}
+
+impl<C: Channel> RecordingChannel<C> {
+    fn events(&self) -> &[TranscriptEvent] {
+        &self.events
+    }
+
+    fn into_events(self) -> Vec<TranscriptEvent> {
+        self.events
+    }
+}
+
+impl<C: Channel> Channel for RecordingChannel<C> {
+    const BYTES_PER_HASH: usize = C::BYTES_PER_HASH;
+
+    fn trailing_zeros(&self) -> u32 {
+        self.channel.trailing_zeros()
+    }
+
+    fn mix_u32s(&mut self, data: &[u32]) {
+        self.events.push(TranscriptEvent::MixU32s(data.to_vec()));
+        self.channel.mix_u32s(data);
+    }
+
+    fn mix_felts(&mut self, felts: &[SecureField]) {
+        self.events.push(TranscriptEvent::MixFelts(felts.to_vec()));
+        self.channel.mix_felts(felts);
+    }
+
+    fn mix_u64(&mut self, value: u64) {
+        self.events.push(TranscriptEvent::MixU64(value));
+        self.channel.mix_u64(value);
+    }
+
+    fn draw_felt(&mut self) -> SecureField {
+        let felt = self.channel.draw_felt();
+        self.events.push(TranscriptEvent::DrawFelt(felt));
+        felt
+    }
+
+    fn draw_felts(&mut self, n_felts: usize) -> Vec<SecureField> {
+        let felts = self.channel.draw_felts(n_felts);
+        self.events.push(TranscriptEvent::DrawFelts(felts.clone()));
+        felts
+    }
+
+    fn draw_random_bytes(&mut self) -> Vec<u8> {
+        let bytes = self.channel.draw_random_bytes();
+        self.events.push(TranscriptEvent::DrawRandomBytes(bytes.clone()));
+        bytes
+    }
+}
+
+// Wraps a channel and checks every call against a transcript recorded by RecordingChannel,
+// panicking with the index and both events at the first deviation. Draws are compared on their
+// outputs, so a changed input shows up at the call itself and any state drift at the next draw.
+#[derive(Clone, Debug)]
+struct ReplayChannel<C: Channel> {
+    channel: C,
+    expected: Vec<TranscriptEvent>,
+    n_replayed: usize,
+}
+
+impl<C: Channel + Default> ReplayChannel<C> {
+    fn new(expected: Vec<TranscriptEvent>) -> Self {
+        Self { channel: C::default(), expected, n_replayed: 0 }
+    }
+}
+
+impl<C: Channel> ReplayChannel<C> {
+    // Whether every recorded event has been replayed.
+    fn is_complete(&self) -> bool {
+        self.n_replayed == self.expected.len()
+    }
+
+    fn check(&mut self, actual: TranscriptEvent) {
+        let index = self.n_replayed;
+        match self.expected.get(index) {
+            Some(expected) if *expected == actual => self.n_replayed += 1,
+            expected => panic!(
+                "channel transcript diverged at operation {}:\n  expected: {:?}\n  actual:   {:?}",
+                index, expected, actual
+            ),
+        }
+    }
+}
+
+impl<C: Channel> Channel for ReplayChannel<C> {
+    const BYTES_PER_HASH: usize = C::BYTES_PER_HASH;
+
+    fn trailing_zeros(&self) -> u32 {
+        self.channel.trailing_zeros()
+    }
+
+    fn mix_u32s(&mut self, data: &[u32]) {
+        self.check(TranscriptEvent::MixU32s(data.to_vec()));
+        self.channel.mix_u32s(data);
+    }
+
+    fn mix_felts(&mut self, felts: &[SecureField]) {
+        self.check(TranscriptEvent::MixFelts(felts.to_vec()));
+        self.channel.mix_felts(felts);
+    }
+
+    fn mix_u64(&mut self, value: u64) {
+        self.check(TranscriptEvent::MixU64(value));
+        self.channel.mix_u64(value);
+    }
+
+    fn draw_felt(&mut self) -> SecureField {
+        let felt = self.channel.draw_felt();
+        self.check(TranscriptEvent::DrawFelt(felt));
+        felt
+    }
+
+    fn draw_felts(&mut self, n_felts: usize) -> Vec<SecureField> {
+        let felts = self.channel.draw_felts(n_felts);
+        self.check(TranscriptEvent::DrawFelts(felts.clone()));
+        felts
+    }
+
+    fn draw_random_bytes(&mut self) -> Vec<u8> {
+        let bytes = self.channel.draw_random_bytes();
+        self.check(TranscriptEvent::DrawRandomBytes(bytes.clone()));
+        bytes
+    }
+}
+
+// A Blake2s channel starting from an arbitrary digest, e.g. an adversarial state in tests.
+fn blake2s_channel_from_digest(digest: Blake2sHash) -> Blake2sChannel {
+    let mut channel = Blake2sChannel::default();
+    channel.update_digest(digest);
+    channel
+}
+
+// A Poseidon252 channel starting from an arbitrary digest.
+fn poseidon252_channel_from_digest(digest: FieldElement252) -> Poseidon252Channel {
+    let mut channel = Poseidon252Channel::default();
+    channel.update_digest(digest);
+    channel
+}
+
+// Absorbs raw bytes into a Blake2s channel: the new digest is Blake2s(digest || bytes). This is
+// what mix_u32s does after serializing its words little-endian, so four bytes mix exactly like
+// the u32 they encode.
+fn blake2s_mix_bytes(channel: &mut Blake2sChannel, bytes: &[u8]) {
+    let digest = channel.digest();
+    let hash_input = [digest.as_ref(), bytes].concat();
+    channel.update_digest(Blake2sHasher::hash(&hash_input));
+}
+
+// Absorbs raw bytes into a Poseidon252 channel: the new digest is
+// poseidon_hash_many([digest, len, chunk_0, chunk_1, ...]), where each chunk is up to 31 bytes
+// read as a big-endian felt252. The length is hashed explicitly because a felt drops leading zero
+// bytes, so [1, 2, 3] and [0, 1, 2, 3] would otherwise collide.
+fn poseidon252_mix_bytes(channel: &mut Poseidon252Channel, bytes: &[u8]) {
+    let mut felts = vec![channel.digest(), FieldElement252::from(bytes.len() as u64)];
+    felts.extend(bytes.chunks(31).map(|chunk| FieldElement252::from_byte_slice_be(chunk).unwrap()));
+    channel.update_digest(poseidon_hash_many(&felts));
+}
+
+// Mixes u32 words into a Poseidon252 channel with length padding: the words are absorbed as their
+// little-endian bytes through poseidon252_mix_bytes, so the hash input is
+// [digest, 4 * data.len(), chunk_0, ...]. The stock mix_u32s packs words into felts without a
+// length, so inputs differing only in zero words can collide; here the byte length is hashed
+// first, so inputs of different lengths never collide, and inputs of the same length split into
+// the same chunks, each read injectively.
+fn poseidon252_mix_u32s_padded(channel: &mut Poseidon252Channel, data: &[u32]) {
+    let bytes: Vec<u8> = data.iter().flat_map(|word| word.to_le_bytes()).collect();
+    poseidon252_mix_bytes(channel, &bytes);
+}
+
+// Mixes `felts` and then draws one challenge bound to them. Mixing always happens first; drawing
+// first would yield a challenge independent of `felts`.
+fn mix_and_draw_felt(channel: &mut impl Channel, felts: &[SecureField]) -> SecureField {
+    channel.mix_felts(felts);
+    channel.draw_felt()
+}
+
+// A Fiat-Shamir permutation of 0..n by Fisher-Yates: for i from n - 1 down to 1, swap i with
+// draw_uint_below(i + 1). Draws happen in that order, n - 1 of them.
+fn draw_permutation(channel: &mut impl Channel, n: usize) -> Vec<usize> {
+    let mut permutation: Vec<usize> = (0..n).collect();
+    for i in (1..n).rev() {
+        let j = draw_uint_below(channel, (i + 1) as u64) as usize;
+        permutation.swap(i, j);
+    }
+    permutation
+}
+
+// A canonical fingerprint of a channel's full state, for asserting two channels agree. The digest
+// alone misses draws (they advance a private counter without touching it), so the fingerprint is
+// the next draw_random_bytes output of a clone, which depends on both the digest and the counter.
+// It needs only Channel + Clone, so wrappers such as LoggingChannel compare against the channel
+// they wrap. The channel itself is not advanced.
+fn channel_state_digest<C: Channel + Clone>(channel: &C) -> Vec<u8> {
+    channel.clone().draw_random_bytes()
+}
+
+// A snapshot of a channel's full state: the digest and the draw counter (stwo's ChannelTime).
+// Neither is settable on the stwo channels (update_digest resets the counter), so the snapshot is a
+// clone, which works for every channel here.
+#[derive(Clone, Debug)]
+struct ChannelState<C: Channel + Clone>(C);
+
+fn channel_checkpoint<C: Channel + Clone>(channel: &C) -> ChannelState<C> {
+    ChannelState(channel.clone())
+}
+
+// After restoring, the channel draws exactly what it drew after the checkpoint.
+fn channel_restore<C: Channel + Clone>(channel: &mut C, state: ChannelState<C>) {
+    *channel = state.0;
+}
+
+// Builds a hash from a runtime slice (e.g. read from a file), rejecting anything but 32 bytes.
+// Blake2sHash already has a panicking From<&[u8]>, which makes the blanket TryFrom infallible,
+// hence a separate fallible constructor.
+fn blake2s_hash_from_slice(bytes: &[u8]) -> Option<Blake2sHash> {
+    <[u8; 32]>::try_from(bytes).ok().map(Blake2sHash)
+}
+
+// Blake2s-256 initial state: the IV with the parameter block (32-byte digest, no key, fanout and
+// depth 1) folded into the first word.
+const BLAKE2S_256_INITIAL_STATE: [u32; 8] = [
+    0x6A09E667 ^ 0x01010020,
+    0xBB67AE85,
+    0x3C6EF372,
+    0xA54FF53A,
+    0x510E527F,
+    0x9B05688C,
+    0x1F83D9AB,
+    0x5BE0CD19,
+];
+
+// The digest a Blake2s channel at `digest` moves to when the nonce is mixed in, as the scalar
+// channel computes it. This is the reference grind_batch_digests is checked against.
+fn grind_digest(digest: Blake2sHash, nonce: u64) -> Blake2sHash {
+    let mut channel = Blake2sChannel::default();
+    channel.update_digest(digest);
+    channel.mix_u64(nonce);
+    channel.digest()
+}
+
+// Checks one (channel digest, nonce, pow_bits) grind claim with the scalar channel: the claim holds
+// if mixing the nonce into a channel at that digest leaves at least pow_bits trailing zeros.
+fn verify_grind_claim(digest: Blake2sHash, nonce: u64, pow_bits: u32) -> bool {
+    let mut channel = Blake2sChannel::default();
+    channel.update_digest(digest);
+    channel.mix_u64(nonce);
+    channel.trailing_zeros() >= pow_bits
+}
+
+// The grind_digest of each claim, 16 per SIMD Blake2s compression. Mixing a nonce hashes the
+// 40-byte message digest || nonce_low || nonce_high (little-endian words), which fits one final
+// block, so each claim is one lane of compress16. A last partial chunk leaves its unused lanes zero
+// and ignores them.
+fn grind_batch_digests(claims: &[(Blake2sHash, u64, u32)]) -> Vec<Blake2sHash> {
+    claims
+        .chunks(N_LANES)
+        .flat_map(|chunk| {
+            let message_word = |lane: usize, word: usize| {
+                chunk.get(lane).map_or(0, |(digest, nonce, _)| match word {
+                    0..=7 => u32::from_le_bytes(digest.0[4 * word..4 * word + 4].try_into().unwrap()),
+                    8 => *nonce as u32,
+                    9 => (*nonce >> 32) as u32,
+                    _ => 0,
+                })
+            };
+            let msg_vecs: [u32x16; 16] =
+                std::array::from_fn(|word| u32x16::from_array(std::array::from_fn(|lane| message_word(lane, word))));
+            let h_vecs: [u32x16; 8] = BLAKE2S_256_INITIAL_STATE.map(u32x16::splat);
+            let new_digests = compress16(
+                h_vecs,
+                msg_vecs,
+                u32x16::splat(40),
+                u32x16::splat(0),
+                u32x16::splat(u32::MAX),
+                u32x16::splat(0),
+            )
+            .map(|words| words.to_array());
+
+            (0..chunk.len())
+                .map(|lane| Blake2sHash(std::array::from_fn(|i| new_digests[i / 4][lane].to_le_bytes()[i % 4])))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+// Checks a batch of grind claims on grind_batch_digests: trailing zeros are read from the low 128
+// bits of each new digest, as Blake2sChannel::trailing_zeros does. Matches verify_grind_claim claim
+// by claim.
+fn verify_grind_batch(claims: &[(Blake2sHash, u64, u32)]) -> Vec<bool> {
+    grind_batch_digests(claims)
+        .iter()
+        .zip(claims)
+        .map(|(digest, &(_, _, pow_bits))| {
+            u128::from_le_bytes(std::array::from_fn(|i| digest.0[i])).trailing_zeros() >= pow_bits
+        })
+        .collect()
+}
+
+// Mirrors Queries::generate so ports can replicate the exact draw sequence. Each query is the low
+// `log_domain_size` bits of a little-endian u32 taken from draw_random_bytes. Exactly `n_queries`
+// values are drawn and collisions are deduplicated, so the result can hold fewer than `n_queries`
+// distinct positions; no extra values are drawn to make up for them. Returns the sorted
+// positions and the number of values drawn.
+fn draw_query_positions(channel: &mut impl Channel, log_domain_size: u32, n_queries: usize) -> (Vec<usize>, usize) {
+    let max_query = (1u32 << log_domain_size) - 1;
+    let mut positions = std::collections::BTreeSet::new();
+    let mut n_drawn = 0;
+    while n_drawn < n_queries {
+        for chunk in channel.draw_random_bytes().chunks_exact(4) {
+            positions.insert((u32::from_le_bytes(chunk.try_into().unwrap()) & max_query) as usize);
+            n_drawn += 1;
+            if n_drawn == n_queries {
+                break;
+            }
+        }
+    }
+    (positions.into_iter().collect(), n_drawn)
+}
+
+// The variant that draws until `n_queries` distinct positions are found: values are drawn in the
+// same order as draw_query_positions, duplicates are skipped, and drawing stops at the value that
+// completes the set. The prefix of draws is shared, so when no collision occurs both agree
+// exactly. Returns the sorted positions and the number of values drawn, at least `n_queries`.
+// Panics if the domain has fewer than `n_queries` positions, where the loop could not finish.
+fn draw_distinct_query_positions(channel: &mut impl Channel, log_domain_size: u32, n_queries: usize) -> (Vec<usize>, usize) {
+    assert!(
+        n_queries <= 1 << log_domain_size,
+        "cannot draw {} distinct queries from a domain of size {}",
+        n_queries,
+        1u64 << log_domain_size
+    );
+    let max_query = (1u32 << log_domain_size) - 1;
+    let mut positions = std::collections::BTreeSet::new();
+    let mut n_drawn = 0;
+    while positions.len() < n_queries {
+        for chunk in channel.draw_random_bytes().chunks_exact(4) {
+            positions.insert((u32::from_le_bytes(chunk.try_into().unwrap()) & max_query) as usize);
+            n_drawn += 1;
+            if positions.len() == n_queries {
+                break;
+            }
+        }
+    }
+    (positions.into_iter().collect(), n_drawn)
+}
+
+// Draws a uniform integer in [0, n) by rejection sampling. Each attempt reads a little-endian u64
+// from the first 8 bytes of draw_random_bytes and is rejected if it falls in the biased tail
+// above the largest multiple of n, so every residue is equally likely. Panics if n is zero.
+fn draw_uint_below(channel: &mut impl Channel, n: u64) -> u64 {
+    assert!(n > 0, "n must be positive");
+    let zone = u64::MAX - (u64::MAX % n);
+    loop {
+        let bytes = channel.draw_random_bytes();
+        let value = u64::from_le_bytes(bytes[..8].try_into().unwrap());
+        if value < zone {
+            return value % n;
+        }
+    }
+}
+
+// Draws `count` integers in [0, n). Channel output is consumed exactly as `count` sequential calls
+// to draw_uint_below, in order, so ports must reproduce the same sequence of draws.
+fn draw_uints_below(channel: &mut impl Channel, n: u64, count: usize) -> Vec<u64> {
+    (0..count).map(|_| draw_uint_below(channel, n)).collect()
+}
+
+// Draws `n_bits` bits, least-significant bit of each byte first, from as many draw_random_bytes
+// calls as needed. Leftover bits of the last draw are discarded, so consecutive calls never share
+// a draw.
+fn draw_bits(channel: &mut impl Channel, n_bits: usize) -> Vec<bool> {
+    let mut bits = Vec::with_capacity(n_bits);
+    while bits.len() < n_bits {
+        for byte in channel.draw_random_bytes() {
+            bits.extend((0..8).map(|i| (byte >> i) & 1 == 1));
+        }
+    }
+    bits.truncate(n_bits);
+    bits
+}
+
+fn u32s_to_json(words: &[u32]) -> serde_json::Value {
+    serde_json::Value::Array(words.iter().map(|&w| serde_json::Value::Number(w.into())).collect())
+}
+
+pub(crate) fn generate_channel_vectors(test_vectors: &mut Vec<TestVector>) {
+    generate_mix_u64_vectors(test_vectors);
+    generate_mix_circle_point_vectors(test_vectors);
+    generate_grind_batch_vectors(test_vectors);
+    generate_from_digest_vectors(test_vectors);
+    generate_query_position_vectors(test_vectors);
+    generate_distinct_query_position_vectors(test_vectors);
+    generate_draw_uints_below_vectors(test_vectors);
+    generate_blake2s_hash_from_slice_vectors(test_vectors);
+    generate_state_digest_vectors(test_vectors);
+    generate_checkpoint_vectors(test_vectors);
+    generate_sample_ood_vectors(test_vectors);
+    generate_counting_channel_vectors(test_vectors);
+    generate_recording_channel_vectors(test_vectors);
+    generate_random_bytes_len_vectors(test_vectors);
+    generate_sha256_channel_vectors(test_vectors);
+    generate_draw_permutation_vectors(test_vectors);
+    generate_mix_and_draw_felt_vectors(test_vectors);
+    generate_keccak256_channel_vectors(test_vectors);
+    generate_draw_uint_below_distribution_vectors(test_vectors);
+    generate_draw_bits_vectors(test_vectors);
+    generate_mix_bytes_vectors(test_vectors);
+    generate_poseidon252_padding_vectors(test_vectors);
+    generate_replay_channel_vectors(test_vectors);
+}
+
+fn generate_mix_u64_vectors(test_vectors: &mut Vec<TestVector>) {
+    let test_values = vec![0u64, 1, 0xffff_ffff, 0x1_0000_0000, 0x0123_4567_89ab_cdef, u64::MAX];
+    for (i, &value) in test_values.iter().enumerate() {
+        // Blake2s
+        let words = blake2s_mix_u64_words(value);
+        let mut via_u64 = Blake2sChannel::default();
+        via_u64.mix_u64(value);
+        let mut via_u32s = Blake2sChannel::default();
+        via_u32s.mix_u32s(&words);
+        test_vectors.push(TestVector {
+            operation: "blake2s_mix_u64".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("value".to_string(), serde_json::Value::String(value.to_string()));
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: {
+                let mut map = HashMap::new();
+                map.insert("u32_words".to_string(), u32s_to_json(&words));
+                map.insert("matches_mix_u32s".to_string(), serde_json::Value::Bool(via_u64.digest() == via_u32s.digest()));
+                map
+            },
+            output: serde_json::Value::String(format!("{:x}", via_u64.digest())),
+        });
+
+        // Poseidon252
+        let words = poseidon252_mix_u64_words(value);
+        let mut via_u64 = Poseidon252Channel::default();
+        via_u64.mix_u64(value);
+        let mut via_u32s = Poseidon252Channel::default();
+        via_u32s.mix_u32s(&words);
+        test_vectors.push(TestVector {
+            operation: "poseidon252_mix_u64".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("value".to_string(), serde_json::Value::String(value.to_string()));
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: {
+                let mut map = HashMap::new();
+                map.insert("u32_words".to_string(), u32s_to_json(&words));
+                map.insert("matches_mix_u32s".to_string(), serde_json::Value::Bool(via_u64.digest() == via_u32s.digest()));
+                map
+            },
+            output: serde_json::Value::String(format!("{:x}", via_u64.digest())),
+        });
+    }
+}
+
+fn generate_mix_circle_point_vectors(test_vectors: &mut Vec<TestVector>) {
+    let points = [SECURE_FIELD_CIRCLE_GEN, SECURE_FIELD_CIRCLE_GEN.double(), -SECURE_FIELD_CIRCLE_GEN];
+    for (i, &point) in points.iter().enumerate() {
+        let unmixed = Blake2sChannel::default();
+        let mut channel = Blake2sChannel::default();
+        mix_circle_point(&mut channel, point);
+        let mut other = Blake2sChannel::default();
+        mix_circle_point(&mut other, point);
+        let mut via_felts = Blake2sChannel::default();
+        via_felts.mix_felts(&[point.x, point.y]);
+
+        test_vectors.push(TestVector {
+            operation: "mix_circle_point".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("x".to_string(), qm31_to_json(&point.x));
+                map.insert("y".to_string(), qm31_to_json(&point.y));
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: {
+                let mut map = HashMap::new();
+                map.insert("matches_fresh_channel".to_string(), serde_json::Value::Bool(channel.digest() == other.digest()));
+                map.insert("matches_mix_felts".to_string(), serde_json::Value::Bool(channel.digest() == via_felts.digest()));
+                map.insert("differs_from_unmixed".to_string(), serde_json::Value::Bool(channel.digest() != unmixed.digest()));
+                map
+            },
+            output: serde_json::Value::String(format!("{:x}", channel.digest())),
+        });
+    }
+}
+
+fn generate_grind_batch_vectors(test_vectors: &mut Vec<TestVector>) {
+    let mut claims = Vec::new();
+    let mut expected = Vec::new();
+    // Enough claims for one full chunk of 16 lanes and a partial one
+    for (i, pow_bits) in [1u32, 5, 10, 1, 2, 3, 4, 6, 8, 12].into_iter().enumerate() {
+        let mut channel = Blake2sChannel::default();
+        channel.mix_u64(i as u64);
+        let nonce = SimdBackend::grind(&channel, pow_bits);
+
+        // The nonce found by grinding is valid...
+        claims.push((channel.digest(), nonce, pow_bits));
+        expected.push(true);
+
+        // ...and a nonce whose hash has too few trailing zeros is not.
+        let bad_nonce = (0..)
+            .find(|&candidate| {
+                let mut check = channel.clone();
+                check.mix_u64(candidate);
+                check.trailing_zeros() < pow_bits
+            })
+            .unwrap();
+        claims.push((channel.digest(), bad_nonce, pow_bits));
+        expected.push(false);
+    }
+
+    // The hand-built SIMD block (IV, parameter block, message layout) must reproduce the scalar
+    // channel exactly, digest by digest; any drift fails the generator instead of a flag.
+    let batch_digests = grind_batch_digests(&claims);
+    let scalar_digests: Vec<Blake2sHash> = claims.iter().map(|&(digest, nonce, _)| grind_digest(digest, nonce)).collect();
+    assert_eq!(batch_digests, scalar_digests, "SIMD grind digests diverge from the scalar channel");
+    let results = verify_grind_batch(&claims);
+    let scalar_results: Vec<bool> =
+        claims.iter().map(|&(digest, nonce, pow_bits)| verify_grind_claim(digest, nonce, pow_bits)).collect();
+    assert_eq!(results, scalar_results, "SIMD grind checks diverge from the scalar channel");
+    test_vectors.push(TestVector {
+        operation: "verify_grind_batch".to_string(),
+        inputs: {
+            let mut map = HashMap::new();
+            map.insert("claims".to_string(), serde_json::Value::Array(
+                claims.iter().map(|(digest, nonce, pow_bits)| serde_json::json!({
+                    "digest": format!("{:x}", digest),
+                    "nonce": nonce.to_string(),
+                    "pow_bits": pow_bits
+                })).collect()
+            ));
+            map
+        },
+        intermediates: {
+            let mut map = HashMap::new();
+            map.insert("matches_expected".to_string(), serde_json::Value::Bool(results == expected));
+            map.insert("matches_scalar".to_string(), serde_json::Value::Bool(results == scalar_results));
+            map.insert("new_digests".to_string(), serde_json::Value::Array(
+                batch_digests.iter().map(|digest| serde_json::Value::String(format!("{:x}", digest))).collect()
+            ));
+            map
+        },
+        output: serde_json::Value::Array(results.iter().map(|&ok| serde_json::Value::Bool(ok)).collect()),
+    });
+}
+
+fn generate_from_digest_vectors(test_vectors: &mut Vec<TestVector>) {
+    // Blake2s
+    let digest = Blake2sHash(std::array::from_fn(|i| i as u8));
+    let default_draw = Blake2sChannel::default().draw_felt();
+    let custom_draw = blake2s_channel_from_digest(digest).draw_felt();
+    let same_digest_draw = blake2s_channel_from_digest(digest).draw_felt();
+    test_vectors.push(TestVector {
+        operation: "blake2s_from_digest".to_string(),
+        inputs: {
+            let mut map = HashMap::new();
+            map.insert("digest".to_string(), serde_json::Value::String(format!("{:x}", digest)));
+            map
+        },
+        intermediates: {
+            let mut map = HashMap::new();
+            map.insert("default_first_draw".to_string(), qm31_to_json(&default_draw));
+            map.insert("differs_from_default".to_string(), serde_json::Value::Bool(custom_draw != default_draw));
+            map.insert("matches_same_digest".to_string(), serde_json::Value::Bool(custom_draw == same_digest_draw));
+            map
+        },
+        output: qm31_to_json(&custom_draw),
+    });
+
+    // Poseidon252
+    let digest = FieldElement252::from(0x0123_4567_89ab_cdefu64);
+    let default_draw = Poseidon252Channel::default().draw_felt();
+    let custom_draw = poseidon252_channel_from_digest(digest).draw_felt();
+    let same_digest_draw = poseidon252_channel_from_digest(digest).draw_felt();
+    test_vectors.push(TestVector {
+        operation: "poseidon252_from_digest".to_string(),
+        inputs: {
+            let mut map = HashMap::new();
+            map.insert("digest".to_string(), serde_json::Value::String(format!("{:x}", digest)));
+            map
+        },
+        intermediates: {
+            let mut map = HashMap::new();
+            map.insert("default_first_draw".to_string(), qm31_to_json(&default_draw));
+            map.insert("differs_from_default".to_string(), serde_json::Value::Bool(custom_draw != default_draw));
+            map.insert("matches_same_digest".to_string(), serde_json::Value::Bool(custom_draw == same_digest_draw));
+            map
+        },
+        output: qm31_to_json(&custom_draw),
+    });
+}
+
+fn generate_query_position_vectors(test_vectors: &mut Vec<TestVector>) {
+    // Tiny domains make collisions frequent.
+    let cases = [(2u32, 10usize), (3, 5), (10, 20)];
+    for (i, &(log_domain_size, n_queries)) in cases.iter().enumerate() {
+        let (positions, n_drawn) = draw_query_positions(&mut Blake2sChannel::default(), log_domain_size, n_queries);
+        let queries = Queries::generate(&mut Blake2sChannel::default(), log_domain_size, n_queries);
+
+        test_vectors.push(TestVector {
+            operation: "query_positions".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("log_domain_size".to_string(), serde_json::Value::Number(log_domain_size.into()));
+                map.insert("n_queries".to_string(), serde_json::Value::Number(n_queries.into()));
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: {
+                let mut map = HashMap::new();
+                map.insert("n_drawn".to_string(), serde_json::Value::Number(n_drawn.into()));
+                map.insert("n_distinct".to_string(), serde_json::Value::Number(positions.len().into()));
+                map.insert("matches_queries_generate".to_string(), serde_json::Value::Bool(positions == queries.positions));
+                map
+            },
+            output: serde_json::Value::Array(positions.iter().map(|&p| serde_json::Value::Number(p.into())).collect()),
+        });
+    }
+}
+
+fn generate_distinct_query_position_vectors(test_vectors: &mut Vec<TestVector>) {
+    // Tiny domains make collisions frequent; (2, 4) needs the whole domain.
+    let cases = [(2u32, 4usize), (3, 6), (10, 20)];
+    for (i, &(log_domain_size, n_queries)) in cases.iter().enumerate() {
+        let (positions, n_drawn) = draw_distinct_query_positions(&mut Blake2sChannel::default(), log_domain_size, n_queries);
+        let (mirrored, _) = draw_query_positions(&mut Blake2sChannel::default(), log_domain_size, n_queries);
+
+        test_vectors.push(TestVector {
+            operation: "distinct_query_positions".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("log_domain_size".to_string(), serde_json::Value::Number(log_domain_size.into()));
+                map.insert("n_queries".to_string(), serde_json::Value::Number(n_queries.into()));
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: {
+                let mut map = HashMap::new();
+                map.insert("n_drawn".to_string(), serde_json::Value::Number(n_drawn.into()));
+                map.insert("has_n_queries_distinct".to_string(), serde_json::Value::Bool(positions.len() == n_queries));
+                map.insert("n_drawn_at_least_n_queries".to_string(), serde_json::Value::Bool(n_drawn >= n_queries));
+                map.insert("contains_mirrored_positions".to_string(), serde_json::Value::Bool(
+                    mirrored.iter().all(|position| positions.contains(position))
+                ));
+                map
+            },
+            output: serde_json::Value::Array(positions.iter().map(|&p| serde_json::Value::Number(p.into())).collect()),
+        });
+    }
+}
+
+fn generate_draw_uints_below_vectors(test_vectors: &mut Vec<TestVector>) {
+    let cases = [(1u64, 4usize), (10, 8), (1 << 20, 5), (u64::MAX - 1, 3)];
+    for (i, &(n, count)) in cases.iter().enumerate() {
+        let batch = draw_uints_below(&mut Blake2sChannel::default(), n, count);
+        let mut channel = Blake2sChannel::default();
+        let sequential: Vec<u64> = (0..count).map(|_| draw_uint_below(&mut channel, n)).collect();
+
+        test_vectors.push(TestVector {
+            operation: "draw_uints_below".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("n".to_string(), serde_json::Value::String(n.to_string()));
+                map.insert("count".to_string(), serde_json::Value::Number(count.into()));
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: {
+                let mut map = HashMap::new();
+                map.insert("matches_sequential".to_string(), serde_json::Value::Bool(batch == sequential));
+                map.insert("all_below_n".to_string(), serde_json::Value::Bool(batch.iter().all(|&v| v < n)));
+                map
+            },
+            output: serde_json::Value::Array(batch.iter().map(|v| serde_json::Value::String(v.to_string())).collect()),
+        });
+    }
+}
+
+fn generate_blake2s_hash_from_slice_vectors(test_vectors: &mut Vec<TestVector>) {
+    for len in [31usize, 32, 33] {
+        let bytes: Vec<u8> = (0..len).map(|i| (i * 7) as u8).collect();
+        let hash = blake2s_hash_from_slice(&bytes);
+
+        test_vectors.push(TestVector {
+            operation: "blake2s_hash_from_slice".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("bytes".to_string(), serde_json::Value::Array(
+                    bytes.iter().map(|&b| serde_json::Value::Number(b.into())).collect()
+                ));
+                map
+            },
+            intermediates: {
+                let mut map = HashMap::new();
+                map.insert("ok".to_string(), serde_json::Value::Bool(hash.is_some()));
+                if let Some(hash) = hash {
+                    map.insert("as_ref_round_trips".to_string(), serde_json::Value::Bool(hash.as_ref() == bytes.as_slice()));
+                }
+                map
+            },
+            output: match hash {
+                Some(hash) => serde_json::Value::String(format!("{:x}", hash)),
+                None => serde_json::Value::Null,
+            },
+        });
+    }
+}
+
+fn generate_state_digest_vectors(test_vectors: &mut Vec<TestVector>) {
+    fn run(channel: &mut impl Channel) {
+        channel.mix_u64(42);
+        channel.draw_felt();
+        channel.mix_u32s(&[1, 2, 3]);
+        channel.draw_felts(2);
+    }
+    let mut a = Blake2sChannel::default();
+    let mut b = Blake2sChannel::default();
+    run(&mut a);
+    run(&mut b);
+    let same_ops_equal = channel_state_digest(&a) == channel_state_digest(&b);
+    let digest_before_draw = channel_state_digest(&b);
+    let same_digest_before_draw = a.digest() == b.digest();
+    b.draw_felt();
+    let extra_draw_diverges = channel_state_digest(&a) != channel_state_digest(&b);
+    let hash_digest_unchanged_by_draw = a.digest() == b.digest();
+    // The logging wrapper forwards every call, so it fingerprints like the channel it wraps.
+    let mut logging = LoggingChannel::<Blake2sChannel>::default();
+    run(&mut logging);
+    let logging_matches_plain = channel_state_digest(&logging) == channel_state_digest(&a);
+
+    test_vectors.push(TestVector {
+        operation: "state_digest".to_string(),
+        inputs: HashMap::new(),
+        intermediates: {
+            let mut map = HashMap::new();
+            map.insert("same_ops_equal".to_string(), serde_json::Value::Bool(same_ops_equal && same_digest_before_draw));
+            map.insert("extra_draw_diverges".to_string(), serde_json::Value::Bool(extra_draw_diverges));
+            map.insert("hash_digest_unchanged_by_draw".to_string(), serde_json::Value::Bool(hash_digest_unchanged_by_draw));
+            map.insert("logging_channel_matches_plain".to_string(), serde_json::Value::Bool(logging_matches_plain));
+            map
+        },
+        output: serde_json::Value::Array(
+            digest_before_draw.iter().map(|&b| serde_json::Value::Number(b.into())).collect()
+        ),
+    });
+}
+
+fn checkpoint_vector<C: Channel + Clone>(channel_name: &str, mut channel: C) -> TestVector {
+    channel.mix_u64(42);
+    let before = channel.draw_felt();
+    let state = channel_checkpoint(&channel);
+    let after_checkpoint = channel.draw_felts(3);
+    channel.mix_u32s(&[1, 2, 3]);
+    channel_restore(&mut channel, state);
+    let after_restore = channel.draw_felts(3);
+
+    TestVector {
+        operation: "channel_checkpoint".to_string(),
+        inputs: {
+            let mut map = HashMap::new();
+            map.insert("channel".to_string(), serde_json::Value::String(channel_name.to_string()));
+            map
+        },
+        intermediates: {
+            let mut map = HashMap::new();
+            map.insert("draw_before_checkpoint".to_string(), qm31_to_json(&before));
+            map.insert("redraws_match".to_string(), serde_json::Value::Bool(after_checkpoint == after_restore));
+            map
+        },
+        output: serde_json::Value::Array(after_restore.iter().map(qm31_to_json).collect()),
+    }
+}
+
+fn generate_checkpoint_vectors(test_vectors: &mut Vec<TestVector>) {
+    test_vectors.push(checkpoint_vector("blake2s", Blake2sChannel::default()));
+    test_vectors.push(checkpoint_vector("poseidon252", Poseidon252Channel::default()));
+}
+
+fn generate_sample_ood_vectors(test_vectors: &mut Vec<TestVector>) {
+    for seed in [0u64, 1, 2] {
+        let mut channel = Blake2sChannel::default();
+        channel.mix_u64(seed);
+        let point = sample_ood(&mut channel.clone());
+        let again = sample_ood(&mut channel.clone());
+
+        test_vectors.push(TestVector {
+            operation: "sample_ood".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("mixed_u64".to_string(), serde_json::Value::String(seed.to_string()));
+                map
+            },
+            intermediates: {
+                let mut map = HashMap::new();
+                map.insert("on_circle".to_string(), serde_json::Value::Bool(point.x * point.x + point.y * point.y == SecureField::one()));
+                map.insert("off_base_circle".to_string(), serde_json::Value::Bool(!is_base_circle_point(&point)));
+                map.insert("reproducible".to_string(), serde_json::Value::Bool(point == again));
+                map
+            },
+            output: serde_json::json!({
+                "x": qm31_to_json(&point.x),
+                "y": qm31_to_json(&point.y)
+            }),
+        });
+    }
+}
+
+fn generate_counting_channel_vectors(test_vectors: &mut Vec<TestVector>) {
+    let x = SECURE_FIELD_CIRCLE_GEN.x;
+    let y = SECURE_FIELD_CIRCLE_GEN.y;
+    let mut channel = CountingChannel::<Blake2sChannel>::default();
+    let mut plain = Blake2sChannel::default();
+
+    let mut counts = Vec::new();
+    channel.mix_u64(7);
+    plain.mix_u64(7);
+    counts.push(channel.n_bytes_mixed());
+    channel.mix_felts(&[x, y]);
+    plain.mix_felts(&[x, y]);
+    counts.push(channel.n_bytes_mixed());
+    channel.mix_u32s(&[1, 2, 3]);
+    plain.mix_u32s(&[1, 2, 3]);
+    counts.push(channel.n_bytes_mixed());
+    let draw = channel.draw_felt();
+    let plain_draw = plain.draw_felt();
+    counts.push(channel.n_bytes_mixed());
+
+    test_vectors.push(TestVector {
+        operation: "counting_channel".to_string(),
+        inputs: {
+            let mut map = HashMap::new();
+            map.insert("operations".to_string(), serde_json::json!(["mix_u64", "mix_felts(2)", "mix_u32s(3)", "draw_felt"]));
+            map
+        },
+        intermediates: {
+            let mut map = HashMap::new();
+            map.insert("transcript_unchanged".to_string(), serde_json::Value::Bool(draw == plain_draw));
+            map
+        },
+        output: serde_json::Value::Array(counts.iter().map(|&n| serde_json::Value::Number(n.into())).collect()),
+    });
+}
+
+fn transcript_event_to_json(event: &TranscriptEvent) -> serde_json::Value {
+    match event {
+        TranscriptEvent::MixU32s(words) => serde_json::json!({ "mix_u32s": u32s_to_json(words) }),
+        TranscriptEvent::MixFelts(felts) => serde_json::json!({ "mix_felts": felts.iter().map(qm31_to_json).collect::<Vec<_>>() }),
+        TranscriptEvent::MixU64(value) => serde_json::json!({ "mix_u64": value }),
+        TranscriptEvent::DrawFelt(felt) => serde_json::json!({ "draw_felt": qm31_to_json(felt) }),
+        TranscriptEvent::DrawFelts(felts) => serde_json::json!({ "draw_felts": felts.iter().map(qm31_to_json).collect::<Vec<_>>() }),
+        TranscriptEvent::DrawRandomBytes(bytes) => serde_json::json!({ "draw_random_bytes": bytes }),
+    }
+}
+
+fn generate_recording_channel_vectors(test_vectors: &mut Vec<TestVector>) {
+    let felts = [SECURE_FIELD_CIRCLE_GEN.x, SECURE_FIELD_CIRCLE_GEN.y];
+    let mut plain = Blake2sChannel::default();
+    let mut recorder = RecordingChannel::<Blake2sChannel>::default();
+
+    // The same calls on both channels; the plain channel's results give the expected events.
+    plain.mix_u64(7);
+    recorder.mix_u64(7);
+    plain.mix_felts(&felts);
+    recorder.mix_felts(&felts);
+    let felt = plain.draw_felt();
+    recorder.draw_felt();
+    plain.mix_u32s(&[1, 2, 3]);
+    recorder.mix_u32s(&[1, 2, 3]);
+    let drawn = plain.draw_felts(3);
+    recorder.draw_felts(3);
+    let bytes = plain.draw_random_bytes();
+    recorder.draw_random_bytes();
+
+    let expected = vec![
+        TranscriptEvent::MixU64(7),
+        TranscriptEvent::MixFelts(felts.to_vec()),
+        TranscriptEvent::DrawFelt(felt),
+        TranscriptEvent::MixU32s(vec![1, 2, 3]),
+        TranscriptEvent::DrawFelts(drawn),
+        TranscriptEvent::DrawRandomBytes(bytes),
+    ];
+    test_vectors.push(TestVector {
+        operation: "recording_channel".to_string(),
+        inputs: HashMap::new(),
+        intermediates: {
+            let mut map = HashMap::new();
+            map.insert("same_digest".to_string(), serde_json::Value::Bool(recorder.channel.digest() == plain.digest()));
+            map.insert("same_state".to_string(), serde_json::Value::Bool(
+                channel_state_digest(&recorder.channel) == channel_state_digest(&plain)
+            ));
+            map.insert("events_match_calls".to_string(), serde_json::Value::Bool(recorder.events() == expected.as_slice()));
+            map
+        },
+        output: serde_json::Value::Array(recorder.events().iter().map(transcript_event_to_json).collect()),
+    });
+}
+
+fn generate_random_bytes_len_vectors(test_vectors: &mut Vec<TestVector>) {
+    let cases = [
+        ("blake2s", Blake2sChannel::default().draw_random_bytes(), BLAKE2S_RANDOM_BYTES_LEN, Blake2sChannel::BYTES_PER_HASH),
+        ("poseidon252", Poseidon252Channel::default().draw_random_bytes(), POSEIDON252_RANDOM_BYTES_LEN, Poseidon252Channel::BYTES_PER_HASH),
+    ];
+    for (channel, bytes, expected_len, bytes_per_hash) in cases {
+        test_vectors.push(TestVector {
+            operation: "draw_random_bytes_len".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("channel".to_string(), serde_json::Value::String(channel.to_string()));
+                map
+            },
+            intermediates: {
+                let mut map = HashMap::new();
+                map.insert("bytes_per_hash".to_string(), serde_json::Value::Number(bytes_per_hash.into()));
+                map.insert("matches_constant".to_string(), serde_json::Value::Bool(bytes.len() == expected_len));
+                map.insert("bytes".to_string(), serde_json::Value::Array(
+                    bytes.iter().map(|&b| serde_json::Value::Number(b.into())).collect()
+                ));
+                map
+            },
+            output: serde_json::Value::Number(bytes.len().into()),
+        });
+    }
+}
+
+fn generate_sha256_channel_vectors(test_vectors: &mut Vec<TestVector>) {
+    let digest_hex = |digest: [u8; 32]| digest.iter().map(|b| format!("{:02x}", b)).collect::<String>();
+    let run = |channel: &mut Sha256Channel| {
+        channel.mix_u64(7);
+        channel.mix_felts(&[SECURE_FIELD_CIRCLE_GEN.x, SECURE_FIELD_CIRCLE_GEN.y]);
+        channel.draw_felts(3)
+    };
+
+    // Two fresh channels agree after the same operations and diverge after one extra mix.
+    let mut first = Sha256Channel::default();
+    let mut second = Sha256Channel::default();
+    let first_felts = run(&mut first);
+    let second_felts = run(&mut second);
+    let same_digest = first.digest() == second.digest();
+    second.mix_u32s(&[1]);
+    let bytes = first.draw_random_bytes();
+    test_vectors.push(TestVector {
+        operation: "sha256_channel".to_string(),
+        inputs: {
+            let mut map = HashMap::new();
+            map.insert("operations".to_string(), serde_json::json!(["mix_u64(7)", "mix_felts(2)", "draw_felts(3)"]));
+            map
+        },
+        intermediates: {
+            let mut map = HashMap::new();
+            map.insert("same_operations_same_digest".to_string(), serde_json::Value::Bool(same_digest && first_felts == second_felts));
+            map.insert("extra_mix_changes_digest".to_string(), serde_json::Value::Bool(first.digest() != second.digest()));
+            map.insert("bytes_len_matches".to_string(), serde_json::Value::Bool(bytes.len() == SHA256_BYTES_PER_HASH));
+            map.insert("trailing_zeros".to_string(), serde_json::Value::Number(first.trailing_zeros().into()));
+            map
+        },
+        output: serde_json::json!({
+            "digest": digest_hex(first.digest()),
+            "felts": first_felts.iter().map(qm31_to_json).collect::<Vec<_>>()
+        }),
+    });
+}
+
+fn generate_draw_permutation_vectors(test_vectors: &mut Vec<TestVector>) {
+    for n in [0usize, 1, 2, 10, 64] {
+        let permutation = draw_permutation(&mut Blake2sChannel::default(), n);
+        let again = draw_permutation(&mut Blake2sChannel::default(), n);
+        let mut sorted = permutation.clone();
+        sorted.sort_unstable();
+
+        test_vectors.push(TestVector {
+            operation: "draw_permutation".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("n".to_string(), serde_json::Value::Number(n.into()));
+                map
+            },
+            intermediates: {
+                let mut map = HashMap::new();
+                map.insert("is_permutation".to_string(), serde_json::Value::Bool(sorted == (0..n).collect::<Vec<_>>()));
+                map.insert("reproducible".to_string(), serde_json::Value::Bool(permutation == again));
+                map
+            },
+            output: serde_json::Value::Array(permutation.iter().map(|&i| serde_json::Value::Number(i.into())).collect()),
+        });
+    }
+}
+
+fn generate_mix_and_draw_felt_vectors(test_vectors: &mut Vec<TestVector>) {
+    let cases: Vec<Vec<SecureField>> = vec![
+        vec![],
+        vec![SECURE_FIELD_CIRCLE_GEN.x],
+        vec![SECURE_FIELD_CIRCLE_GEN.x, SECURE_FIELD_CIRCLE_GEN.y],
+    ];
+    for (i, felts) in cases.iter().enumerate() {
+        let challenge = mix_and_draw_felt(&mut Blake2sChannel::default(), felts);
+
+        let mut separate = Blake2sChannel::default();
+        separate.mix_felts(felts);
+        let separate_challenge = separate.draw_felt();
+
+        let mut swapped = Blake2sChannel::default();
+        let swapped_challenge = swapped.draw_felt();
+        swapped.mix_felts(felts);
+
+        test_vectors.push(TestVector {
+            operation: "mix_and_draw_felt".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("felts".to_string(), serde_json::Value::Array(felts.iter().map(qm31_to_json).collect()));
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: {
+                let mut map = HashMap::new();
+                map.insert("matches_mix_then_draw".to_string(), serde_json::Value::Bool(challenge == separate_challenge));
+                map.insert("differs_from_draw_then_mix".to_string(), serde_json::Value::Bool(challenge != swapped_challenge));
+                map
+            },
+            output: qm31_to_json(&challenge),
+        });
+    }
+}
+
+fn generate_keccak256_channel_vectors(test_vectors: &mut Vec<TestVector>) {
+    // Time tracking: mixing starts a new challenge, drawing advances the counter within it.
+    let mut channel = Keccak256Channel::default();
+    let mut times = vec![(channel.n_challenges(), channel.n_sent())];
+    channel.draw_felt();
+    times.push((channel.n_challenges(), channel.n_sent()));
+    channel.draw_random_bytes();
+    times.push((channel.n_challenges(), channel.n_sent()));
+    channel.mix_u32s(&[1, 2, 3]);
+    times.push((channel.n_challenges(), channel.n_sent()));
+    test_vectors.push(TestVector {
+        operation: "keccak256_channel_time".to_string(),
+        inputs: {
+            let mut map = HashMap::new();
+            map.insert("operations".to_string(), serde_json::json!(["draw_felt", "draw_random_bytes", "mix_u32s(3)"]));
+            map
+        },
+        intermediates: HashMap::new(),
+        output: serde_json::Value::Array(times.iter().map(|&(n_challenges, n_sent)| serde_json::json!({
+            "n_challenges": n_challenges,
+            "n_sent": n_sent
+        })).collect()),
+    });
+
+    // Distinct draws
+    let mut channel = Keccak256Channel::default();
+    let felts = channel.draw_felts(10);
+    let distinct = felts.iter().enumerate().all(|(i, a)| felts[..i].iter().all(|b| a != b));
+    let bytes = channel.draw_random_bytes();
+    test_vectors.push(TestVector {
+        operation: "keccak256_draws".to_string(),
+        inputs: HashMap::new(),
+        intermediates: {
+            let mut map = HashMap::new();
+            map.insert("felts_distinct".to_string(), serde_json::Value::Bool(distinct));
+            map.insert("bytes_len_matches".to_string(), serde_json::Value::Bool(bytes.len() == KECCAK256_BYTES_PER_HASH));
+            map.insert("random_bytes".to_string(), serde_json::Value::Array(
+                bytes.iter().map(|&b| serde_json::Value::Number(b.into())).collect()
+            ));
+            map
+        },
+        output: serde_json::Value::Array(felts.iter().map(qm31_to_json).collect()),
+    });
+
+    // mix_u64 equals its u32 decomposition, low word first
+    for (i, value) in [0u64, 1, 0x0123_4567_89ab_cdef, u64::MAX].into_iter().enumerate() {
+        let mut via_u64 = Keccak256Channel::default();
+        via_u64.mix_u64(value);
+        let mut via_u32s = Keccak256Channel::default();
+        via_u32s.mix_u32s(&blake2s_mix_u64_words(value));
+        test_vectors.push(TestVector {
+            operation: "keccak256_mix_u64".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("value".to_string(), serde_json::Value::String(value.to_string()));
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: {
+                let mut map = HashMap::new();
+                map.insert("matches_mix_u32s".to_string(), serde_json::Value::Bool(via_u64.digest() == via_u32s.digest()));
+                map
+            },
+            output: serde_json::Value::String(Keccak256Hash(via_u64.digest()).to_string()),
+        });
+    }
+
+    // Mixing a Merkle root
+    let root = Keccak256MerkleHasher::hash_node(None, &[1u32.into(), 2u32.into()]);
+    let mut channel = Keccak256Channel::default();
+    Keccak256MerkleChannel::mix_root(&mut channel, root);
+    test_vectors.push(TestVector {
+        operation: "keccak256_mix_root".to_string(),
+        inputs: {
+            let mut map = HashMap::new();
+            map.insert("root".to_string(), serde_json::Value::String(root.to_string()));
+            map
+        },
+        intermediates: {
+            let mut map = HashMap::new();
+            map.insert("n_challenges".to_string(), serde_json::Value::Number(channel.n_challenges().into()));
+            map
+        },
+        output: serde_json::Value::String(Keccak256Hash(channel.digest()).to_string()),
+    });
+}
+
+// Statistical smoke test: 10k draws below 100 stay in range and hit every bucket.
+fn generate_draw_uint_below_distribution_vectors(test_vectors: &mut Vec<TestVector>) {
+    let n = 100u64;
+    let n_draws = 10_000;
+    let draws = draw_uints_below(&mut Blake2sChannel::default(), n, n_draws);
+    let mut buckets = vec![0usize; n as usize];
+    for &draw in &draws {
+        if draw < n {
+            buckets[draw as usize] += 1;
+        }
+    }
+
+    test_vectors.push(TestVector {
+        operation: "draw_uint_below_distribution".to_string(),
+        inputs: {
+            let mut map = HashMap::new();
+            map.insert("n".to_string(), serde_json::Value::Number(n.into()));
+            map.insert("n_draws".to_string(), serde_json::Value::Number(n_draws.into()));
+            map
+        },
+        intermediates: {
+            let mut map = HashMap::new();
+            map.insert("all_below_n".to_string(), serde_json::Value::Bool(draws.iter().all(|&draw| draw < n)));
+            map.insert("all_buckets_hit".to_string(), serde_json::Value::Bool(buckets.iter().all(|&count| count > 0)));
+            map.insert("min_bucket".to_string(), serde_json::Value::Number((*buckets.iter().min().unwrap()).into()));
+            map.insert("max_bucket".to_string(), serde_json::Value::Number((*buckets.iter().max().unwrap()).into()));
+            map
+        },
+        output: serde_json::Value::Array(buckets.iter().map(|&count| serde_json::Value::Number(count.into())).collect()),
+    });
+}
+
+fn generate_draw_bits_vectors(test_vectors: &mut Vec<TestVector>) {
+    let bits_to_json =
+        |bits: &[bool]| serde_json::Value::Array(bits.iter().map(|&bit| serde_json::Value::Bool(bit)).collect());
+
+    // Blake2s draws 256 bits per call and Poseidon252 248, so 300 bits spans two draws for both.
+    for n_bits in [0usize, 1, 8, 13, 300] {
+        for (channel_name, bits, first_byte) in [
+            ("blake2s", draw_bits(&mut Blake2sChannel::default(), n_bits), Blake2sChannel::default().draw_random_bytes()[0]),
+            ("poseidon252", draw_bits(&mut Poseidon252Channel::default(), n_bits), Poseidon252Channel::default().draw_random_bytes()[0]),
+        ] {
+            let expected_prefix: Vec<bool> = (0..8.min(n_bits)).map(|i| (first_byte >> i) & 1 == 1).collect();
+            test_vectors.push(TestVector {
+                operation: "draw_bits".to_string(),
+                inputs: {
+                    let mut map = HashMap::new();
+                    map.insert("channel".to_string(), serde_json::Value::String(channel_name.to_string()));
+                    map.insert("n_bits".to_string(), serde_json::Value::Number(n_bits.into()));
+                    map
+                },
+                intermediates: {
+                    let mut map = HashMap::new();
+                    map.insert("first_byte".to_string(), serde_json::Value::Number(first_byte.into()));
+                    map.insert("prefix_matches_first_byte".to_string(), serde_json::Value::Bool(bits[..expected_prefix.len()] == expected_prefix[..]));
+                    map.insert("len_matches".to_string(), serde_json::Value::Bool(bits.len() == n_bits));
+                    map
+                },
+                output: bits_to_json(&bits),
+            });
+        }
+    }
+
+    // Chained calls are deterministic and each starts on a fresh draw.
+    let mut channel = Blake2sChannel::default();
+    let first = draw_bits(&mut channel, 5);
+    let second = draw_bits(&mut channel, 5);
+    let mut replay = Blake2sChannel::default();
+    let replayed = [draw_bits(&mut replay, 5), draw_bits(&mut replay, 5)];
+    test_vectors.push(TestVector {
+        operation: "draw_bits_chained".to_string(),
+        inputs: {
+            let mut map = HashMap::new();
+            map.insert("n_bits_per_call".to_string(), serde_json::Value::Number(5.into()));
+            map
+        },
+        intermediates: {
+            let mut map = HashMap::new();
+            map.insert("deterministic".to_string(), serde_json::Value::Bool(replayed == [first.clone(), second.clone()]));
+            map.insert("channel_state_matches_two_draws".to_string(), serde_json::Value::Bool({
+                let mut two_draws = Blake2sChannel::default();
+                two_draws.draw_random_bytes();
+                two_draws.draw_random_bytes();
+                channel_state_digest(&channel) == channel_state_digest(&two_draws)
+            }));
+            map
+        },
+        output: serde_json::Value::Array(vec![bits_to_json(&first), bits_to_json(&second)]),
+    });
+}
+
+fn generate_mix_bytes_vectors(test_vectors: &mut Vec<TestVector>) {
+    let bytes_to_json =
+        |bytes: &[u8]| serde_json::Value::Array(bytes.iter().map(|&b| serde_json::Value::Number(b.into())).collect());
+    let inputs: Vec<Vec<u8>> = vec![vec![], vec![0], vec![1, 2, 3], vec![1, 2, 3, 0], vec![0, 1, 2, 3], (0..31).collect(), (0..32).collect()];
+
+    for (i, bytes) in inputs.iter().enumerate() {
+        let mut blake2s = Blake2sChannel::default();
+        blake2s_mix_bytes(&mut blake2s, bytes);
+        let mut poseidon252 = Poseidon252Channel::default();
+        poseidon252_mix_bytes(&mut poseidon252, bytes);
+        test_vectors.push(TestVector {
+            operation: "mix_bytes".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("bytes".to_string(), bytes_to_json(bytes));
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: HashMap::new(),
+            output: serde_json::json!({
+                "blake2s_digest": blake2s.digest().to_string(),
+                "poseidon252_digest": format!("{:#x}", poseidon252.digest())
+            }),
+        });
+    }
+
+    // Trailing and leading zeros change the digest
+    let poseidon252_digest = |bytes: &[u8]| {
+        let mut channel = Poseidon252Channel::default();
+        poseidon252_mix_bytes(&mut channel, bytes);
+        channel.digest()
+    };
+    let blake2s_digest = |bytes: &[u8]| {
+        let mut channel = Blake2sChannel::default();
+        blake2s_mix_bytes(&mut channel, bytes);
+        channel.digest()
+    };
+    test_vectors.push(TestVector {
+        operation: "mix_bytes_length_sensitive".to_string(),
+        inputs: {
+            let mut map = HashMap::new();
+            map.insert("a".to_string(), bytes_to_json(&[1, 2, 3]));
+            map.insert("b".to_string(), bytes_to_json(&[1, 2, 3, 0]));
+            map.insert("c".to_string(), bytes_to_json(&[0, 1, 2, 3]));
+            map
+        },
+        intermediates: HashMap::new(),
+        output: serde_json::json!({
+            "poseidon252_a_ne_b": poseidon252_digest(&[1, 2, 3]) != poseidon252_digest(&[1, 2, 3, 0]),
+            "poseidon252_a_ne_c": poseidon252_digest(&[1, 2, 3]) != poseidon252_digest(&[0, 1, 2, 3]),
+            "blake2s_a_ne_b": blake2s_digest(&[1, 2, 3]) != blake2s_digest(&[1, 2, 3, 0])
+        }),
+    });
+
+    // Blake2s: four little-endian bytes mix like the u32 they encode
+    for (i, word) in [0u32, 1, 0x0403_0201, u32::MAX].into_iter().enumerate() {
+        let mut via_bytes = Blake2sChannel::default();
+        blake2s_mix_bytes(&mut via_bytes, &word.to_le_bytes());
+        let mut via_u32s = Blake2sChannel::default();
+        via_u32s.mix_u32s(&[word]);
+        test_vectors.push(TestVector {
+            operation: "blake2s_mix_bytes_vs_mix_u32s".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("word".to_string(), serde_json::Value::Number(word.into()));
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: HashMap::new(),
+            output: serde_json::Value::Bool(via_bytes.digest() == via_u32s.digest()),
+        });
+    }
+}
+
+fn generate_poseidon252_padding_vectors(test_vectors: &mut Vec<TestVector>) {
+    let stock_digest = |data: &[u32]| {
+        let mut channel = Poseidon252Channel::default();
+        channel.mix_u32s(data);
+        channel.digest()
+    };
+    let padded_digest = |data: &[u32]| {
+        let mut channel = Poseidon252Channel::default();
+        poseidon252_mix_u32s_padded(&mut channel, data);
+        channel.digest()
+    };
+
+    // Pairs differing only in zero words: whether the stock mix collides, and the padded digests
+    let pairs: [(&[u32], &[u32]); 4] = [(&[], &[0]), (&[1], &[1, 0]), (&[1], &[0, 1]), (&[1, 2], &[0, 0, 1, 2])];
+    for (i, (a, b)) in pairs.into_iter().enumerate() {
+        test_vectors.push(TestVector {
+            operation: "poseidon252_mix_u32s_padded".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("a".to_string(), u32s_to_json(a));
+                map.insert("b".to_string(), u32s_to_json(b));
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: {
+                let mut map = HashMap::new();
+                map.insert("stock_collides".to_string(), serde_json::Value::Bool(stock_digest(a) == stock_digest(b)));
+                map.insert("padded_collides".to_string(), serde_json::Value::Bool(padded_digest(a) == padded_digest(b)));
+                map
+            },
+            output: serde_json::json!({
+                "a_digest": format!("{:#x}", padded_digest(a)),
+                "b_digest": format!("{:#x}", padded_digest(b))
+            }),
+        });
+    }
+}
+
+// A fixed run of channel calls, parametrized by the value it mixes first.
+fn run_transcript_scenario(channel: &mut impl Channel, first_value: u64) {
+    channel.mix_u64(first_value);
+    channel.mix_felts(&[SECURE_FIELD_CIRCLE_GEN.x, SECURE_FIELD_CIRCLE_GEN.y]);
+    channel.draw_felt();
+    channel.mix_u32s(&[1, 2, 3]);
+    channel.draw_felts(3);
+    channel.draw_random_bytes();
+}
+
+fn generate_replay_channel_vectors(test_vectors: &mut Vec<TestVector>) {
+    let mut recorder = RecordingChannel::<Blake2sChannel>::default();
+    run_transcript_scenario(&mut recorder, 7);
+    let transcript = recorder.into_events();
+
+    for (case, first_value) in [("identical", 7u64), ("altered_mix", 8)] {
+        let mut replay = ReplayChannel::<Blake2sChannel>::new(transcript.clone());
+        let result = catch_unwind_silent(|| run_transcript_scenario(&mut replay, first_value));
+        let panic_message = result
+            .as_ref()
+            .err()
+            .and_then(|payload| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+        test_vectors.push(TestVector {
+            operation: "replay_channel".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("case".to_string(), serde_json::Value::String(case.to_string()));
+                map.insert("recorded_first_value".to_string(), serde_json::Value::Number(7.into()));
+                map.insert("replayed_first_value".to_string(), serde_json::Value::Number(first_value.into()));
+                map
+            },
+            intermediates: {
+                let mut map = HashMap::new();
+                map.insert("transcript_len".to_string(), serde_json::Value::Number(transcript.len().into()));
+                map.insert("panic_message".to_string(), serde_json::Value::String(panic_message));
+                map
+            },
+            output: serde_json::json!({
+                "replayed": result.is_ok(),
+                "complete": result.is_ok() && replay.is_complete()
+            }),
+        });
+    }
+}
@@ -0,0 +1,119 @@
+//! A Fiat-Shamir channel over any 32-byte `digest::Digest`, mirroring `Blake2sChannel`:
+//! - Mixing hashes `digest || input`, where u32 words and felt coordinates are little-endian, and
+//!   replaces the digest.
+//! - `draw_random_bytes` hashes `digest || n_sent as u32 LE || 0u8` and increments `n_sent`.
+//! - Base felts are drawn eight at a time from one 32-byte draw, as little-endian u32s. The whole
+//!   draw is retried unless every u32 is below `2P`, then each is reduced into `[0, P)`, so drawn
+//!   felts are distributed exactly as in the Blake2s channel.
+//!
+//! `Keccak256Channel` and `Sha256Channel` are aliases of this type.
+
+use std::marker::PhantomData;
+
+use digest::consts::U32;
+use digest::{Digest, OutputSizeUser};
+use stwo_prover::core::channel::Channel;
+use stwo_prover::core::fields::m31::{BaseField, P};
+use stwo_prover::core::fields::qm31::SecureField;
+
+pub(crate) const DIGEST_CHANNEL_BYTES_PER_HASH: usize = 32;
+const FELTS_PER_HASH: usize = 8;
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct DigestChannel<D> {
+    digest: [u8; 32],
+    n_challenges: usize,
+    n_sent: usize,
+    _hasher: PhantomData<D>,
+}
+
+impl<D: Digest + OutputSizeUser<OutputSize = U32>> DigestChannel<D> {
+    pub(crate) fn digest(&self) -> [u8; 32] {
+        self.digest
+    }
+
+    // Number of digest updates so far, i.e. the index of the current challenge.
+    pub(crate) fn n_challenges(&self) -> usize {
+        self.n_challenges
+    }
+
+    // Number of draws since the last digest update.
+    pub(crate) fn n_sent(&self) -> usize {
+        self.n_sent
+    }
+
+    pub(crate) fn update_digest(&mut self, new_digest: [u8; 32]) {
+        self.digest = new_digest;
+        self.n_challenges += 1;
+        self.n_sent = 0;
+    }
+
+    pub(crate) fn mix_bytes(&mut self, bytes: &[u8]) {
+        let mut hasher = D::new();
+        hasher.update(self.digest);
+        hasher.update(bytes);
+        self.update_digest(hasher.finalize().into());
+    }
+
+    fn draw_base_felts(&mut self) -> [BaseField; FELTS_PER_HASH] {
+        // Retry probability for each round is ~ 2^(-28).
+        loop {
+            let bytes = self.draw_random_bytes();
+            let u32s: [u32; FELTS_PER_HASH] =
+                std::array::from_fn(|i| u32::from_le_bytes(bytes[4 * i..4 * i + 4].try_into().unwrap()));
+            if u32s.iter().all(|&x| x < 2 * P) {
+                return u32s.map(|x| BaseField::reduce(x as u64));
+            }
+        }
+    }
+}
+
+impl<D> Channel for DigestChannel<D>
+where
+    D: Digest + OutputSizeUser<OutputSize = U32> + Clone + Default + std::fmt::Debug,
+{
+    const BYTES_PER_HASH: usize = DIGEST_CHANNEL_BYTES_PER_HASH;
+
+    fn trailing_zeros(&self) -> u32 {
+        u128::from_le_bytes(std::array::from_fn(|i| self.digest[i])).trailing_zeros()
+    }
+
+    fn mix_u32s(&mut self, data: &[u32]) {
+        let bytes: Vec<u8> = data.iter().flat_map(|word| word.to_le_bytes()).collect();
+        self.mix_bytes(&bytes);
+    }
+
+    fn mix_felts(&mut self, felts: &[SecureField]) {
+        let bytes: Vec<u8> = felts
+            .iter()
+            .flat_map(|felt| felt.to_m31_array())
+            .flat_map(|limb| limb.0.to_le_bytes())
+            .collect();
+        self.mix_bytes(&bytes);
+    }
+
+    fn mix_u64(&mut self, value: u64) {
+        self.mix_u32s(&[value as u32, (value >> 32) as u32]);
+    }
+
+    fn draw_felt(&mut self) -> SecureField {
+        let felts = self.draw_base_felts();
+        SecureField::from_m31_array(felts[..4].try_into().unwrap())
+    }
+
+    fn draw_felts(&mut self, n_felts: usize) -> Vec<SecureField> {
+        let mut felts = std::iter::from_fn(|| Some(self.draw_base_felts())).flatten();
+        let secure_felts = std::iter::from_fn(|| {
+            Some(SecureField::from_m31_array([felts.next()?, felts.next()?, felts.next()?, felts.next()?]))
+        });
+        secure_felts.take(n_felts).collect()
+    }
+
+    fn draw_random_bytes(&mut self) -> Vec<u8> {
+        let mut hash_input = self.digest.to_vec();
+        hash_input.extend_from_slice(&(self.n_sent as u32).to_le_bytes());
+        hash_input.push(0);
+        self.n_sent += 1;
+        D::digest(hash_input).to_vec()
+    }
+}
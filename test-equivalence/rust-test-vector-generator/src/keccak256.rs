@@ -0,0 +1,77 @@
+//! A Keccak256 Fiat-Shamir channel and Merkle hasher, for interop with EVM verifiers.
+//!
+//! The channel is a `DigestChannel` over Keccak256, so it mirrors `Blake2sChannel` with Keccak256
+//! in place of Blake2s.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
+use stwo_prover::core::channel::MerkleChannel;
+use stwo_prover::core::fields::m31::BaseField;
+use stwo_prover::core::vcs::hash::Hash;
+use stwo_prover::core::vcs::ops::MerkleHasher;
+
+use crate::digest_channel::{DigestChannel, DIGEST_CHANNEL_BYTES_PER_HASH};
+
+pub(crate) const KECCAK256_BYTES_PER_HASH: usize = DIGEST_CHANNEL_BYTES_PER_HASH;
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct Keccak256Hash(pub [u8; 32]);
+
+impl fmt::Display for Keccak256Hash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.0 {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Keccak256Hash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl AsRef<[u8]> for Keccak256Hash {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Hash for Keccak256Hash {}
+
+pub(crate) type Keccak256Channel = DigestChannel<Keccak256>;
+
+// Hashes a node as Keccak256(left || right || values), each value as 4 little-endian bytes.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Keccak256MerkleHasher;
+
+impl MerkleHasher for Keccak256MerkleHasher {
+    type Hash = Keccak256Hash;
+
+    fn hash_node(children_hashes: Option<(Self::Hash, Self::Hash)>, column_values: &[BaseField]) -> Self::Hash {
+        let mut hasher = Keccak256::new();
+        if let Some((left, right)) = children_hashes {
+            hasher.update(left.0);
+            hasher.update(right.0);
+        }
+        for value in column_values {
+            hasher.update(value.0.to_le_bytes());
+        }
+        Keccak256Hash(hasher.finalize().into())
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct Keccak256MerkleChannel;
+
+impl MerkleChannel for Keccak256MerkleChannel {
+    type C = Keccak256Channel;
+    type H = Keccak256MerkleHasher;
+
+    fn mix_root(channel: &mut Self::C, root: <Self::H as MerkleHasher>::Hash) {
+        channel.mix_bytes(&root.0);
+    }
+}
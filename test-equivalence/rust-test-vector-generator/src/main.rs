@@ -1,3 +1,5 @@
+#![feature(portable_simd)]
+
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use stwo_prover::core::fields::m31::{M31, P, pow2147483645};
@@ -5,11 +7,24 @@ use stwo_prover::core::fields::cm31::{CM31, P2};
 use stwo_prover::core::fields::qm31::{QM31, P4, R, SecureField};
 use stwo_prover::core::fields::secure_column::{SecureColumnByCoords, SECURE_EXTENSION_DEGREE};
 use stwo_prover::core::fields::{IntoSlice, FieldExpOps, ComplexConjugate};
-use stwo_prover::core::backend::{CpuBackend, Col, Column};
+use stwo_prover::core::backend::{CpuBackend, Col, Column, ColumnOps};
+use stwo_prover::core::backend::simd::SimdBackend;
+use stwo_prover::core::fields::m31::BaseField;
+use stwo_prover::core::utils::bit_reverse;
 use rand::rngs::SmallRng;
 use rand::{Rng, SeedableRng};
 use num_traits::{Zero, One};
 
+use crate::secure_column_ops::{secure_column_mul_add_assign, secure_column_mul_add_assign_simd};
+
+mod channel;
+mod digest_channel;
+mod keccak256;
+mod merkle;
+mod merkle_ops;
+mod secure_column_ops;
+mod sha256;
+
 #[derive(Serialize, Deserialize, Debug)]
 struct TestVector {
     operation: String,
@@ -26,7 +41,59 @@ struct FieldTestVectors {
     test_vectors: Vec<TestVector>,
 }
 
+// A field limb as it appears in test vector JSON: small values are written as numbers and large
+// ones as decimal strings (to dodge JSON precision issues), so readers accept both.
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+enum JsonLimb {
+    Number(u64),
+    String(String),
+}
+
+impl JsonLimb {
+    // Parses the limb and reduces it into [0, P).
+    fn to_m31(&self) -> Result<M31, String> {
+        let value = match self {
+            JsonLimb::Number(value) => *value,
+            JsonLimb::String(value) => value
+                .parse::<u64>()
+                .map_err(|e| format!("invalid limb {:?}: {}", value, e))?,
+        };
+        Ok(M31::from_u32_unchecked((value % P as u64) as u32))
+    }
+}
+
+// Deserializes an M31 from its serde form (a bare u32), rejecting values >= P. The derived
+// Deserialize on M31 accepts any u32; use this via #[serde(deserialize_with)] on untrusted input.
+fn m31_deserialize_checked<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<M31, D::Error> {
+    let value = u32::deserialize(deserializer)?;
+    if value >= P {
+        return Err(serde::de::Error::custom(format!("M31 value {} is not below P", value)));
+    }
+    Ok(M31::from_u32_unchecked(value))
+}
+
+fn m31_from_json(value: &serde_json::Value) -> Result<M31, String> {
+    serde_json::from_value::<JsonLimb>(value.clone())
+        .map_err(|e| e.to_string())?
+        .to_m31()
+}
+
+// Parses a QM31 written as an array of four limbs, each a number or a decimal string.
+fn qm31_from_json(value: &serde_json::Value) -> Result<QM31, String> {
+    let limbs = serde_json::from_value::<[JsonLimb; 4]>(value.clone()).map_err(|e| e.to_string())?;
+    Ok(QM31::from_m31_array([
+        limbs[0].to_m31()?,
+        limbs[1].to_m31()?,
+        limbs[2].to_m31()?,
+        limbs[3].to_m31()?,
+    ]))
+}
+
 fn main() {
+    // Pass --cbor to also write each field's vectors as CBOR next to the JSON file
+    let write_cbor = std::env::args().any(|arg| arg == "--cbor");
+
     // Generate test vectors for all field types
     let mut all_test_vectors = Vec::new();
     
@@ -70,11 +137,35 @@ fn main() {
         test_vectors: secure_column_vectors,
     });
 
+    // Channel test vectors
+    let mut channel_vectors = Vec::new();
+    channel::generate_channel_vectors(&mut channel_vectors);
+    all_test_vectors.push(FieldTestVectors {
+        description: "Test vectors for Fiat-Shamir channel operations".to_string(),
+        field_type: "Channel".to_string(),
+        field_modulus: serde_json::Value::String(P4.to_string()),
+        test_vectors: channel_vectors,
+    });
+
+    // Merkle hashing test vectors
+    let mut merkle_vectors = Vec::new();
+    merkle::generate_merkle_vectors(&mut merkle_vectors);
+    all_test_vectors.push(FieldTestVectors {
+        description: "Test vectors for Merkle node hashing".to_string(),
+        field_type: "Merkle".to_string(),
+        field_modulus: serde_json::Value::String(P4.to_string()),
+        test_vectors: merkle_vectors,
+    });
+
     // Write individual field test vector files
     for field_vectors in &all_test_vectors {
         let filename = format!("../../test-vectors/{}-test-vectors.json", field_vectors.field_type.to_lowercase());
         let json = serde_json::to_string_pretty(field_vectors).unwrap();
         std::fs::write(&filename, json).unwrap();
+        if write_cbor {
+            let file = std::fs::File::create(filename.replace(".json", ".cbor")).unwrap();
+            ciborium::into_writer(field_vectors, file).unwrap();
+        }
         println!("Generated {} test vectors for {} field operations", 
                 field_vectors.test_vectors.len(), field_vectors.field_type);
     }
@@ -91,6 +182,9 @@ fn generate_m31_vectors(test_vectors: &mut Vec<TestVector>) {
     // Generate test vectors for basic operations
     generate_basic_ops_vectors(test_vectors);
     
+    // Generate test vectors for unreduced (wide) intermediates
+    generate_wide_ops_vectors(test_vectors);
+
     // Generate test vectors for construction methods
     generate_construction_vectors(test_vectors);
     
@@ -99,15 +193,260 @@ fn generate_m31_vectors(test_vectors: &mut Vec<TestVector>) {
     
     // Generate test vectors for inverse operations
     generate_inverse_vectors(test_vectors);
+
+    // Generate test vectors for square roots
+    generate_sqrt_vectors(test_vectors);
     
+    // Generate test vectors for zero-tolerant inverse
+    generate_inverse_or_zero_vectors(test_vectors);
+
+    // Generate test vectors for batch inverse
+    generate_batch_inverse_vectors(test_vectors);
+
+    // Generate test vectors for batch inverse with its prefix products
+    generate_batch_inverse_with_products_vectors(test_vectors);
+
     // Generate test vectors for pow2147483645 function
     generate_pow2147483645_vectors(test_vectors);
+
+    // Generate test vectors for FieldExpOps::pow with arbitrary exponents
+    generate_pow_vectors(test_vectors);
     
     // Generate test vectors for into_slice operation
     generate_into_slice_vectors(test_vectors);
     
     // Generate test vectors for edge cases
     generate_edge_case_vectors(test_vectors);
+
+    // Generate test vectors for JSON parsing of number and string limbs
+    generate_json_parsing_vectors(test_vectors);
+
+    // Generate test vectors for the slice mismatch report
+    generate_m31_slices_diff_vectors(test_vectors);
+
+    // Generate test vectors for single-element byte encoding
+    generate_m31_byte_vectors(test_vectors);
+
+    // Generate test vectors for serde round trips
+    generate_serde_vectors(test_vectors);
+}
+
+// Encodes an M31 as 4 little-endian bytes, the same bytes into_slice produces for it.
+fn m31_to_le_bytes(value: &M31) -> [u8; 4] {
+    value.0.to_le_bytes()
+}
+
+// Inverse of m31_to_le_bytes. Returns None if the value is not in [0, P).
+fn m31_from_le_bytes(bytes: &[u8; 4]) -> Option<M31> {
+    let value = u32::from_le_bytes(*bytes);
+    if value >= P {
+        return None;
+    }
+    Some(M31::from_u32_unchecked(value))
+}
+
+fn generate_m31_byte_vectors(test_vectors: &mut Vec<TestVector>) {
+    let bytes_to_json =
+        |bytes: &[u8]| serde_json::Value::Array(bytes.iter().map(|&b| serde_json::Value::Number(b.into())).collect());
+
+    // Round trips of random elements
+    let mut rng = SmallRng::seed_from_u64(17);
+    for i in 0..10 {
+        let value: M31 = rng.gen();
+        let bytes = m31_to_le_bytes(&value);
+        let decoded = m31_from_le_bytes(&bytes);
+        test_vectors.push(TestVector {
+            operation: "to_le_bytes".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("value".to_string(), serde_json::Value::Number(value.0.into()));
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: {
+                let mut map = HashMap::new();
+                map.insert("round_trip_matches".to_string(), serde_json::Value::Bool(decoded == Some(value)));
+                map
+            },
+            output: bytes_to_json(&bytes),
+        });
+    }
+
+    // Out-of-range values are rejected
+    for (i, &value) in [0, P - 1, P, 2 * P - 1, u32::MAX].iter().enumerate() {
+        let bytes = value.to_le_bytes();
+        let decoded = m31_from_le_bytes(&bytes);
+        test_vectors.push(TestVector {
+            operation: "from_le_bytes".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("bytes".to_string(), bytes_to_json(&bytes));
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: HashMap::new(),
+            output: match decoded {
+                Some(value) => serde_json::Value::Number(value.0.into()),
+                None => serde_json::Value::Null,
+            },
+        });
+    }
+
+    // Concatenated encodings are exactly into_slice, and decode back
+    let mut rng = SmallRng::seed_from_u64(23);
+    let elements: Vec<M31> = (0..8).map(|_| rng.gen()).collect();
+    let concatenated: Vec<u8> = elements.iter().flat_map(m31_to_le_bytes).collect();
+    let decoded: Vec<Option<M31>> =
+        concatenated.chunks_exact(4).map(|chunk| m31_from_le_bytes(chunk.try_into().unwrap())).collect();
+    test_vectors.push(TestVector {
+        operation: "le_bytes_into_slice".to_string(),
+        inputs: {
+            let mut map = HashMap::new();
+            map.insert("elements".to_string(), serde_json::Value::Array(
+                elements.iter().map(|e| serde_json::Value::Number(e.0.into())).collect()
+            ));
+            map
+        },
+        intermediates: {
+            let mut map = HashMap::new();
+            map.insert("matches_into_slice".to_string(), serde_json::Value::Bool(concatenated == M31::into_slice(&elements)));
+            map.insert("round_trip_matches".to_string(), serde_json::Value::Bool(
+                decoded.iter().zip(&elements).all(|(decoded, element)| *decoded == Some(*element))
+            ));
+            map
+        },
+        output: bytes_to_json(&concatenated),
+    });
+}
+
+// A wrapper deserializing its M31 with range validation.
+#[derive(Deserialize, Debug)]
+struct CheckedM31(#[serde(deserialize_with = "m31_deserialize_checked")] M31);
+
+fn generate_serde_vectors(test_vectors: &mut Vec<TestVector>) {
+    let mut rng = SmallRng::seed_from_u64(16);
+
+    // Round trip of a QM31 vector through bincode
+    let values: Vec<QM31> = (0..8).map(|_| rng.gen()).collect();
+    let encoded = bincode::serialize(&values).unwrap();
+    let decoded: Vec<QM31> = bincode::deserialize(&encoded).unwrap();
+    test_vectors.push(TestVector {
+        operation: "bincode_round_trip".to_string(),
+        inputs: {
+            let mut map = HashMap::new();
+            map.insert("values".to_string(), serde_json::Value::Array(values.iter().map(qm31_to_json).collect()));
+            map
+        },
+        intermediates: {
+            let mut map = HashMap::new();
+            map.insert("encoded_len".to_string(), serde_json::Value::Number(encoded.len().into()));
+            map.insert("round_trips".to_string(), serde_json::Value::Bool(decoded == values));
+            map
+        },
+        output: serde_json::Value::String(encoded.iter().map(|b| format!("{:02x}", b)).collect()),
+    });
+
+    // Range validation on deserialization
+    for (i, value) in [0u32, P - 1, P, u32::MAX].into_iter().enumerate() {
+        let encoded = bincode::serialize(&value).unwrap();
+        let unchecked = bincode::deserialize::<M31>(&encoded).map(|m| m.0);
+        let checked = bincode::deserialize::<CheckedM31>(&encoded).map(|m| m.0 .0);
+        test_vectors.push(TestVector {
+            operation: "deserialize_checked".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("value".to_string(), serde_json::Value::Number(value.into()));
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: {
+                let mut map = HashMap::new();
+                map.insert("derived_accepts".to_string(), serde_json::Value::Bool(unchecked.is_ok()));
+                map
+            },
+            output: match checked {
+                Ok(value) => serde_json::Value::Number(value.into()),
+                Err(_) => serde_json::Value::Null,
+            },
+        });
+    }
+}
+
+// Describes how two M31 slices differ: the first differing index with both values and the total
+// number of differences, or a length mismatch. Returns None if they are equal. Meant for
+// comparing large columns, where dumping both vectors is useless.
+fn m31_slices_diff(a: &[M31], b: &[M31]) -> Option<String> {
+    if a.len() != b.len() {
+        return Some(format!("slices differ in length: {} vs {}", a.len(), b.len()));
+    }
+    let mut differences = a.iter().zip(b).enumerate().filter(|(_, (x, y))| x != y);
+    let (first_index, (first_a, first_b)) = differences.next()?;
+    let n_differences = 1 + differences.count();
+    Some(format!(
+        "slices differ at {} of {} indices; first at index {}: {} vs {}",
+        n_differences,
+        a.len(),
+        first_index,
+        first_a.0,
+        first_b.0
+    ))
+}
+
+// Asserts two M31 slices are equal, reporting the first mismatch instead of both slices.
+fn assert_m31_slices_eq(a: &[M31], b: &[M31]) {
+    if let Some(diff) = m31_slices_diff(a, b) {
+        panic!("{}", diff);
+    }
+}
+
+// Runs f and catches a panic without printing it. The default hook writes every panic message to
+// stderr, which is noise for panics the vectors expect; the previous hook is restored afterwards.
+pub(crate) fn catch_unwind_silent<R>(f: impl FnOnce() -> R) -> std::thread::Result<R> {
+    let hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(|_| {}));
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(f));
+    std::panic::set_hook(hook);
+    result
+}
+
+fn generate_m31_slices_diff_vectors(test_vectors: &mut Vec<TestVector>) {
+    let base: Vec<M31> = (0..1u32 << 16).map(M31::from).collect();
+    let mut one_off = base.clone();
+    one_off[40000] = M31::from(7u32);
+    let mut many_off = base.clone();
+    for i in [3, 500, 65535] {
+        many_off[i] += M31::from(1u32);
+    }
+    let shorter = base[..100].to_vec();
+
+    let cases: Vec<(&str, &[M31])> = vec![
+        ("equal", &base[..]),
+        ("one_difference", &one_off[..]),
+        ("three_differences", &many_off[..]),
+        ("length_mismatch", &shorter[..]),
+    ];
+    for (i, (name, other)) in cases.into_iter().enumerate() {
+        let diff = m31_slices_diff(&base, other);
+        let panicked = catch_unwind_silent(|| assert_m31_slices_eq(&base, other)).is_err();
+        test_vectors.push(TestVector {
+            operation: "slices_diff".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("case".to_string(), serde_json::Value::String(name.to_string()));
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: {
+                let mut map = HashMap::new();
+                map.insert("assert_panicked".to_string(), serde_json::Value::Bool(panicked));
+                map
+            },
+            output: match diff {
+                Some(diff) => serde_json::Value::String(diff),
+                None => serde_json::Value::Null,
+            },
+        });
+    }
 }
 
 fn generate_basic_ops_vectors(test_vectors: &mut Vec<TestVector>) {
@@ -157,6 +496,7 @@ fn generate_basic_ops_vectors(test_vectors: &mut Vec<TestVector>) {
         
         // Subtraction test vector
         let difference = m31_x - m31_y;
+        let (difference_with_intermediate, intermediate_sum) = m31_sub_with_intermediate(m31_x, m31_y);
         test_vectors.push(TestVector {
             operation: "sub".to_string(),
             inputs: {
@@ -168,7 +508,9 @@ fn generate_basic_ops_vectors(test_vectors: &mut Vec<TestVector>) {
             },
             intermediates: {
                 let mut map = HashMap::new();
-                map.insert("intermediate_sum".to_string(), serde_json::Value::Number((x + P - y).into()));
+                map.insert("intermediate_sum".to_string(), serde_json::Value::Number(intermediate_sum.into()));
+                map.insert("intermediate_matches_formula".to_string(), serde_json::Value::Bool(intermediate_sum == x + P - y));
+                map.insert("result_matches_sub".to_string(), serde_json::Value::Bool(difference_with_intermediate == difference));
                 map
             },
             output: serde_json::Value::Number(difference.0.into()),
@@ -190,6 +532,68 @@ fn generate_basic_ops_vectors(test_vectors: &mut Vec<TestVector>) {
     }
 }
 
+// The unreduced product of two M31 limbs: the `product_u64` that M31::reduce consumes in `mul`.
+fn m31_mul_wide(a: M31, b: M31) -> u64 {
+    (a.0 as u64) * (b.0 as u64)
+}
+
+// The unreduced sum of two M31 limbs, before the partial reduction in `add`.
+fn m31_add_wide(a: M31, b: M31) -> u64 {
+    (a.0 as u64) + (b.0 as u64)
+}
+
+// Subtraction as `sub` computes it: the borrow-free intermediate `a + P - b` (below 2P, so it fits
+// a u32), partially reduced into [0, P). Returns the result and the intermediate.
+fn m31_sub_with_intermediate(a: M31, b: M31) -> (M31, u32) {
+    let intermediate_sum = a.0 + P - b.0;
+    (M31::partial_reduce(intermediate_sum), intermediate_sum)
+}
+
+fn generate_wide_ops_vectors(test_vectors: &mut Vec<TestVector>) {
+    let mut rng = SmallRng::seed_from_u64(7);
+    let mut pairs: Vec<(M31, M31)> = (0..20).map(|_| (rng.gen(), rng.gen())).collect();
+    pairs.push((M31::from(P - 1), M31::from(P - 1)));
+
+    for (i, &(a, b)) in pairs.iter().enumerate() {
+        let product_wide = m31_mul_wide(a, b);
+        let sum_wide = m31_add_wide(a, b);
+        test_vectors.push(TestVector {
+            operation: "mul_wide".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("a".to_string(), serde_json::Value::Number(a.0.into()));
+                map.insert("b".to_string(), serde_json::Value::Number(b.0.into()));
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: {
+                let mut map = HashMap::new();
+                map.insert("reduced".to_string(), serde_json::Value::Number(M31::reduce(product_wide).0.into()));
+                map.insert("reduce_matches_mul".to_string(), serde_json::Value::Bool(M31::reduce(product_wide) == a * b));
+                map
+            },
+            output: serde_json::Value::String(product_wide.to_string()),
+        });
+        test_vectors.push(TestVector {
+            operation: "add_wide".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("a".to_string(), serde_json::Value::Number(a.0.into()));
+                map.insert("b".to_string(), serde_json::Value::Number(b.0.into()));
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: {
+                let mut map = HashMap::new();
+                map.insert("reduced".to_string(), serde_json::Value::Number(M31::reduce(sum_wide).0.into()));
+                map.insert("reduce_matches_add".to_string(), serde_json::Value::Bool(M31::reduce(sum_wide) == a + b));
+                map
+            },
+            output: serde_json::Value::Number(sum_wide.into()),
+        });
+    }
+}
+
 fn generate_construction_vectors(test_vectors: &mut Vec<TestVector>) {
     // Test from_u32_unchecked
     let test_values = vec![0, 1, 42, 1000, P-1, P/2];
@@ -208,6 +612,23 @@ fn generate_construction_vectors(test_vectors: &mut Vec<TestVector>) {
         });
     }
     
+    // Test const_from, which reduces in const contexts
+    const TABLE: [M31; 3] = [m31_const_from(P), m31_const_from(P + 1), m31_const_from(5)];
+    let const_from_values = [P, P + 1, 5];
+    for (i, (&val, m31_val)) in const_from_values.iter().zip(TABLE).enumerate() {
+        test_vectors.push(TestVector {
+            operation: "const_from".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("value".to_string(), serde_json::Value::Number(val.into()));
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: HashMap::new(),
+            output: serde_json::Value::Number(m31_val.0.into()),
+        });
+    }
+
     // Test from i32 (including negative values)
     let i32_test_values = vec![-1, -10, -100, 0, 1, 10, 100];
     for (i, &val) in i32_test_values.iter().enumerate() {
@@ -243,6 +664,12 @@ fn generate_construction_vectors(test_vectors: &mut Vec<TestVector>) {
     }
 }
 
+// Reduces `value` modulo P. Unlike `M31::from_u32_unchecked`, the result is always in range, and
+// unlike `M31::from`, it can be used to build constants.
+const fn m31_const_from(value: u32) -> M31 {
+    M31::from_u32_unchecked(value % P)
+}
+
 fn generate_reduction_vectors(test_vectors: &mut Vec<TestVector>) {
     // Test partial_reduce
     let partial_reduce_values = vec![0, 1, P-1, P, P+1, 2*P-1];
@@ -330,6 +757,347 @@ fn generate_inverse_vectors(test_vectors: &mut Vec<TestVector>) {
     }
 }
 
+// Square root in M31. P = 2^31 - 1 is 3 mod 4, so for a residue x, x^((P + 1) / 4) = x^(2^29) is
+// a root: its square is x^((P + 1) / 2) = x * x^((P - 1) / 2) = x by Euler's criterion, and no
+// Tonelli-Shanks is needed. The candidate is squared back to reject non-residues. The other root
+// is its negation.
+fn m31_sqrt(value: M31) -> Option<M31> {
+    let root = value.pow(1 << 29);
+    (root.square() == value).then_some(root)
+}
+
+// Euler's criterion: x is a square iff x = 0 or x^((P - 1) / 2) = 1.
+fn m31_is_quadratic_residue(value: M31) -> bool {
+    value.is_zero() || value.pow(((P - 1) / 2) as u128).is_one()
+}
+
+fn generate_sqrt_vectors(test_vectors: &mut Vec<TestVector>) {
+    let mut rng = SmallRng::seed_from_u64(25);
+    // 4 = 2^2 is a residue; P - 1 = -1 is not, since P = 3 mod 4.
+    let mut test_values = vec![0, 1, 2, 4, 9, P - 1, P - 4];
+    test_values.extend((0..5).map(|_| rng.gen_range(0..P)));
+    for (i, &val) in test_values.iter().enumerate() {
+        let m31_val = M31::from_u32_unchecked(val);
+        let root = m31_sqrt(m31_val);
+        test_vectors.push(TestVector {
+            operation: "sqrt".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("value".to_string(), serde_json::Value::Number(val.into()));
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: {
+                let mut map = HashMap::new();
+                map.insert("is_quadratic_residue".to_string(), serde_json::Value::Bool(m31_is_quadratic_residue(m31_val)));
+                map.insert("root_squares_back".to_string(), serde_json::Value::Bool(root.map_or(true, |root| root.square() == m31_val)));
+                map
+            },
+            output: match root {
+                Some(root) => serde_json::json!({ "root": root.0, "other_root": (-root).0 }),
+                None => serde_json::Value::Null,
+            },
+        });
+    }
+}
+
+// Inverse with the convention that zero maps to zero instead of panicking.
+fn inverse_or_zero<F: FieldExpOps + Zero>(value: &F) -> F {
+    if value.is_zero() {
+        F::zero()
+    } else {
+        value.inverse()
+    }
+}
+
+fn generate_inverse_or_zero_vectors(test_vectors: &mut Vec<TestVector>) {
+    let test_values = vec![0, 1, 2, 42, P-1];
+    for (i, &val) in test_values.iter().enumerate() {
+        let m31_val = M31::from_u32_unchecked(val);
+        let result = inverse_or_zero(&m31_val);
+        test_vectors.push(TestVector {
+            operation: "inverse_or_zero".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("value".to_string(), serde_json::Value::Number(val.into()));
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: {
+                let mut map = HashMap::new();
+                let matches = if m31_val.is_zero() { result.is_zero() } else { result == m31_val.inverse() };
+                map.insert("matches_inverse".to_string(), serde_json::Value::Bool(matches));
+                map
+            },
+            output: serde_json::Value::Number(result.0.into()),
+        });
+    }
+
+    let qm31_values = vec![QM31::zero(), QM31::one(), QM31::from_u32_unchecked(1, 2, 3, 4)];
+    for (i, value) in qm31_values.iter().enumerate() {
+        let result = inverse_or_zero(value);
+        test_vectors.push(TestVector {
+            operation: "qm31_inverse_or_zero".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("value".to_string(), qm31_to_json(value));
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: {
+                let mut map = HashMap::new();
+                let matches = if value.is_zero() { result.is_zero() } else { result == value.inverse() };
+                map.insert("matches_inverse".to_string(), serde_json::Value::Bool(matches));
+                map
+            },
+            output: qm31_to_json(&result),
+        });
+    }
+}
+
+// Montgomery's batch inverse: one pass building prefix products, a single inverse() of the total
+// product, then a backward pass peeling off one element at a time. Zeros are skipped and stay zero,
+// matching inverse_or_zero, so one zero does not poison the batch.
+fn batch_inverse<F: FieldExpOps + Zero + Copy>(values: &[F]) -> Vec<F> {
+    batch_inverse_with_products(values).0
+}
+
+// The exclusive prefix products of the non-zero values, i.e. entry i is the product of the
+// non-zero values before i, and the product of all of them.
+fn prefix_products_of_non_zero<F: FieldExpOps + Zero + Copy>(values: &[F]) -> (Vec<F>, F) {
+    let mut prefix_products = Vec::with_capacity(values.len());
+    let mut product = F::one();
+    for value in values {
+        prefix_products.push(product);
+        if !value.is_zero() {
+            product *= *value;
+        }
+    }
+    (prefix_products, product)
+}
+
+// batch_inverse, also returning the prefix products the trick used (see
+// prefix_products_of_non_zero), so a port can be checked step by step: products[0] is one and each
+// next entry multiplies in the previous value, unless it is zero.
+fn batch_inverse_with_products<F: FieldExpOps + Zero + Copy>(values: &[F]) -> (Vec<F>, Vec<F>) {
+    let mut inverses = values.to_vec();
+    let prefix_products = batch_inverse_in_place_with_products(&mut inverses);
+    (inverses, prefix_products)
+}
+
+fn batch_inverse_in_place<F: FieldExpOps + Zero + Copy>(values: &mut [F]) {
+    batch_inverse_in_place_with_products(values);
+}
+
+// The single pass behind every batch inverse here: inverts values in place and returns the prefix
+// products the backward pass consumed.
+fn batch_inverse_in_place_with_products<F: FieldExpOps + Zero + Copy>(values: &mut [F]) -> Vec<F> {
+    // A single element gains nothing from the trick; its only prefix product is one.
+    if let [value] = values {
+        *value = inverse_or_zero(value);
+        return vec![F::one()];
+    }
+
+    let (prefix_products, product) = prefix_products_of_non_zero(values);
+    let mut inverse = product.inverse();
+    for (value, prefix_product) in values.iter_mut().zip(&prefix_products).rev() {
+        if value.is_zero() {
+            continue;
+        }
+        let original = *value;
+        *value = inverse * *prefix_product;
+        inverse *= original;
+    }
+    prefix_products
+}
+
+fn generate_batch_inverse_vectors(test_vectors: &mut Vec<TestVector>) {
+    let mut rng = SmallRng::seed_from_u64(21);
+    let batches: Vec<Vec<u32>> = vec![
+        vec![],
+        vec![42],
+        vec![0],
+        vec![1, 2, 3, 4],
+        vec![5, 0, P - 1, 0, 19],
+        (0..32).map(|_| rng.gen_range(1..P)).collect(),
+    ];
+    let m31s_to_json =
+        |values: &[M31]| serde_json::Value::Array(values.iter().map(|value| serde_json::Value::Number(value.0.into())).collect());
+
+    for (i, batch) in batches.iter().enumerate() {
+        let values: Vec<M31> = batch.iter().map(|&value| M31::from_u32_unchecked(value)).collect();
+        let inverses = batch_inverse(&values);
+        let mut in_place = values.clone();
+        batch_inverse_in_place(&mut in_place);
+        test_vectors.push(TestVector {
+            operation: "batch_inverse".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("values".to_string(), m31s_to_json(&values));
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: {
+                let mut map = HashMap::new();
+                map.insert("products_are_one".to_string(), serde_json::Value::Bool(
+                    inverses.iter().zip(&values).all(|(inverse, value)| value.is_zero() || (*inverse * *value).is_one())
+                ));
+                map.insert("matches_inverse_or_zero".to_string(), serde_json::Value::Bool(
+                    inverses.iter().zip(&values).all(|(inverse, value)| *inverse == inverse_or_zero(value))
+                ));
+                map.insert("in_place_matches".to_string(), serde_json::Value::Bool(in_place == inverses));
+                map
+            },
+            output: m31s_to_json(&inverses),
+        });
+    }
+
+    // Extension fields
+    let cm31_values = vec![
+        CM31::from_u32_unchecked(1, 2),
+        CM31::zero(),
+        CM31::from_u32_unchecked(P - 1, 7),
+        CM31::from_u32_unchecked(0, 1),
+    ];
+    push_batch_inverse_vector(test_vectors, "cm31_batch_inverse", 0, &cm31_values, |value| {
+        serde_json::json!([value.0.0, value.1.0])
+    });
+    let qm31_batches = vec![
+        vec![QM31::from_u32_unchecked(1, 2, 3, 4)],
+        vec![QM31::one(), QM31::zero(), QM31::from_u32_unchecked(0, 0, 0, 1)],
+        (0..8).map(|_| rng.gen()).collect(),
+    ];
+    for (i, values) in qm31_batches.iter().enumerate() {
+        push_batch_inverse_vector(test_vectors, "qm31_batch_inverse", i, values, qm31_to_json);
+    }
+}
+
+fn generate_batch_inverse_with_products_vectors(test_vectors: &mut Vec<TestVector>) {
+    let mut rng = SmallRng::seed_from_u64(19);
+    let batches: Vec<Vec<u32>> = vec![
+        vec![],
+        vec![42],
+        vec![1, 2, 3, 4],
+        vec![P - 1, 0, 19, 1000, 7],
+        (0..16).map(|_| rng.gen_range(1..P)).collect(),
+    ];
+    let m31s_to_json =
+        |values: &[M31]| serde_json::Value::Array(values.iter().map(|value| serde_json::Value::Number(value.0.into())).collect());
+
+    for (i, batch) in batches.iter().enumerate() {
+        let values: Vec<M31> = batch.iter().map(|&value| M31::from_u32_unchecked(value)).collect();
+        let (inverses, products) = batch_inverse_with_products(&values);
+        let products_reconstruct = products.iter().enumerate().all(|(j, &product)| {
+            product
+                == match j.checked_sub(1) {
+                    None => M31::one(),
+                    Some(prev) if values[prev].is_zero() => products[prev],
+                    Some(prev) => products[prev] * values[prev],
+                }
+        });
+        test_vectors.push(TestVector {
+            operation: "batch_inverse_with_products".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("values".to_string(), m31s_to_json(&values));
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: {
+                let mut map = HashMap::new();
+                map.insert("prefix_products".to_string(), m31s_to_json(&products));
+                map.insert("products_reconstruct".to_string(), serde_json::Value::Bool(products_reconstruct));
+                map.insert("matches_scalar_inverse".to_string(), serde_json::Value::Bool(
+                    inverses.iter().zip(&values).all(|(inverse, value)| value.is_zero() || *inverse == value.inverse())
+                ));
+                map
+            },
+            output: m31s_to_json(&inverses),
+        });
+    }
+}
+
+// Pushes an extension-field batch inverse vector, with the prefix products the trick builds.
+fn push_batch_inverse_vector<F: FieldExpOps + Zero + Copy>(
+    test_vectors: &mut Vec<TestVector>,
+    operation: &str,
+    test_case: usize,
+    values: &[F],
+    to_json: impl Fn(&F) -> serde_json::Value,
+) {
+    let (inverses, prefix_products) = batch_inverse_with_products(values);
+    test_vectors.push(TestVector {
+        operation: operation.to_string(),
+        inputs: {
+            let mut map = HashMap::new();
+            map.insert("values".to_string(), serde_json::Value::Array(values.iter().map(&to_json).collect()));
+            map.insert("test_case".to_string(), serde_json::Value::Number(test_case.into()));
+            map
+        },
+        intermediates: {
+            let mut map = HashMap::new();
+            map.insert("prefix_products".to_string(), serde_json::Value::Array(
+                prefix_products.iter().map(&to_json).collect()
+            ));
+            map.insert("matches_inverse_or_zero".to_string(), serde_json::Value::Bool(
+                inverses.iter().zip(values).all(|(inverse, value)| *inverse == inverse_or_zero(value))
+            ));
+            map
+        },
+        output: serde_json::Value::Array(inverses.iter().map(&to_json).collect()),
+    });
+}
+
+// FieldExpOps::pow (square-and-multiply over a u128 exponent) already exists for all field
+// types. These vectors pin it for a few exponents, including P - 2, where it is the M31 inverse.
+fn generate_pow_vectors(test_vectors: &mut Vec<TestVector>) {
+    let exponents: Vec<u128> = vec![0, 1, 2, 3, 5, 1 << 20, (P - 2) as u128, u128::MAX];
+    let bases = vec![0, 1, 2, 42, P - 1];
+    for (i, &val) in bases.iter().enumerate() {
+        let m31_val = M31::from_u32_unchecked(val);
+        for &exp in &exponents {
+            let result = m31_val.pow(exp);
+            test_vectors.push(TestVector {
+                operation: "pow".to_string(),
+                inputs: {
+                    let mut map = HashMap::new();
+                    map.insert("value".to_string(), serde_json::Value::Number(val.into()));
+                    map.insert("exponent".to_string(), serde_json::Value::String(exp.to_string()));
+                    map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                    map
+                },
+                intermediates: {
+                    let mut map = HashMap::new();
+                    if exp == (P - 2) as u128 && val != 0 {
+                        map.insert("matches_inverse".to_string(), serde_json::Value::Bool(result == m31_val.inverse()));
+                    }
+                    map
+                },
+                output: serde_json::Value::Number(result.0.into()),
+            });
+        }
+    }
+
+    let qm31_values = vec![QM31::one(), QM31::from_u32_unchecked(1, 2, 3, 4)];
+    for (i, value) in qm31_values.iter().enumerate() {
+        for &exp in &exponents {
+            let result = value.pow(exp);
+            test_vectors.push(TestVector {
+                operation: "qm31_pow".to_string(),
+                inputs: {
+                    let mut map = HashMap::new();
+                    map.insert("value".to_string(), qm31_to_json(value));
+                    map.insert("exponent".to_string(), serde_json::Value::String(exp.to_string()));
+                    map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                    map
+                },
+                intermediates: HashMap::new(),
+                output: qm31_to_json(&result),
+            });
+        }
+    }
+}
+
 fn generate_pow2147483645_vectors(test_vectors: &mut Vec<TestVector>) {
     let test_values = vec![1, 2, 19, 42, 1000];
     for (i, &val) in test_values.iter().enumerate() {
@@ -490,14 +1258,16 @@ fn generate_cm31_vectors(test_vectors: &mut Vec<TestVector>) {
             intermediates: {
                 let mut map = HashMap::new();
                 // Show intermediate calculations: (ac - bd) + (ad + bc)i
-                let ac = (a as u64) * (c as u64);
-                let bd = (b as u64) * (d as u64);
-                let ad = (a as u64) * (d as u64);
-                let bc = (b as u64) * (c as u64);
+                let (_, [ac, bd, ad, bc]) = cm31_mul_with_intermediates(cm31_x, cm31_y);
                 map.insert("ac".to_string(), serde_json::Value::Number(ac.into()));
                 map.insert("bd".to_string(), serde_json::Value::Number(bd.into()));
                 map.insert("ad".to_string(), serde_json::Value::Number(ad.into()));
                 map.insert("bc".to_string(), serde_json::Value::Number(bc.into()));
+                let reassembled = CM31(
+                    M31::reduce(ac) - M31::reduce(bd),
+                    M31::reduce(ad) + M31::reduce(bc),
+                );
+                map.insert("reassembled_matches".to_string(), serde_json::Value::Bool(reassembled == product));
                 map
             },
             output: {
@@ -551,6 +1321,9 @@ fn generate_cm31_vectors(test_vectors: &mut Vec<TestVector>) {
         });
     }
     
+    // Test little-endian byte encoding
+    generate_cm31_byte_vectors(test_vectors);
+
     // Test construction methods
     let construction_values = vec![(0, 0), (1, 0), (0, 1), (1, 1), (42, 19), (P-1, P-1)];
     for (i, &(a, b)) in construction_values.iter().enumerate() {
@@ -685,8 +1458,223 @@ fn generate_cm31_vectors(test_vectors: &mut Vec<TestVector>) {
     });
 }
 
+// CM31 multiplication with its four unreduced cross products (ac, bd, ad, bc), where
+// (a + bi)(c + di) = (ac - bd) + (ad + bc)i. Each cross product is below 2^62.
+fn cm31_mul_with_intermediates(x: CM31, y: CM31) -> (CM31, [u64; 4]) {
+    let cross_products = [
+        m31_mul_wide(x.0, y.0),
+        m31_mul_wide(x.1, y.1),
+        m31_mul_wide(x.0, y.1),
+        m31_mul_wide(x.1, y.0),
+    ];
+    (x * y, cross_products)
+}
+
+// Encodes a CM31 as its real then imaginary limb, each as 4 little-endian bytes.
+fn cm31_to_le_bytes(value: &CM31) -> [u8; 8] {
+    let mut bytes = [0u8; 8];
+    bytes[..4].copy_from_slice(&m31_to_le_bytes(&value.0));
+    bytes[4..].copy_from_slice(&m31_to_le_bytes(&value.1));
+    bytes
+}
+
+// Inverse of cm31_to_le_bytes. Returns None if either limb is not in [0, P).
+fn cm31_from_le_bytes(bytes: &[u8; 8]) -> Option<CM31> {
+    let real = m31_from_le_bytes(bytes[..4].try_into().unwrap())?;
+    let imag = m31_from_le_bytes(bytes[4..].try_into().unwrap())?;
+    Some(CM31(real, imag))
+}
+
+// Encodes a QM31 as its two CM31 halves (a, b of a + bu), 16 bytes in limb order.
+fn qm31_to_le_bytes(value: &QM31) -> [u8; 16] {
+    let mut bytes = [0u8; 16];
+    bytes[..8].copy_from_slice(&cm31_to_le_bytes(&value.0));
+    bytes[8..].copy_from_slice(&cm31_to_le_bytes(&value.1));
+    bytes
+}
+
+// Inverse of qm31_to_le_bytes. Returns None if any limb is not in [0, P).
+fn qm31_from_le_bytes(bytes: &[u8; 16]) -> Option<QM31> {
+    let a = cm31_from_le_bytes(bytes[..8].try_into().unwrap())?;
+    let b = cm31_from_le_bytes(bytes[8..].try_into().unwrap())?;
+    Some(QM31(a, b))
+}
+
+// Encodes a list of secure felts as a u32 little-endian count followed by each element's
+// qm31_to_le_bytes, e.g. for variable-length fields of a proof format.
+fn secure_felts_to_bytes(values: &[SecureField]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(4 + 16 * values.len());
+    bytes.extend_from_slice(&(values.len() as u32).to_le_bytes());
+    for value in values {
+        bytes.extend_from_slice(&qm31_to_le_bytes(value));
+    }
+    bytes
+}
+
+// Inverse of secure_felts_to_bytes. Errors if the buffer is shorter or longer than its count
+// implies, or if any limb is not in [0, P).
+fn secure_felts_from_bytes(bytes: &[u8]) -> Result<Vec<SecureField>, String> {
+    let (count, body) = bytes
+        .split_first_chunk::<4>()
+        .ok_or_else(|| format!("Expected a 4-byte length prefix, got {} bytes", bytes.len()))?;
+    let count = u32::from_le_bytes(*count) as usize;
+    if body.len() != 16 * count {
+        return Err(format!("Expected {} bytes for {} elements, got {}", 16 * count, count, body.len()));
+    }
+    body.chunks_exact(16)
+        .enumerate()
+        .map(|(i, chunk)| {
+            qm31_from_le_bytes(chunk.try_into().unwrap()).ok_or_else(|| format!("Element {} has a limb out of range", i))
+        })
+        .collect()
+}
+
+fn generate_qm31_byte_vectors(test_vectors: &mut Vec<TestVector>) {
+    let mut rng = SmallRng::seed_from_u64(24);
+    let bytes_to_json =
+        |bytes: &[u8]| serde_json::Value::Array(bytes.iter().map(|&b| serde_json::Value::Number(b.into())).collect());
+
+    // Round trips of random elements, matching the limbs' into_slice bytes
+    for i in 0..5 {
+        let value: QM31 = rng.gen();
+        let bytes = qm31_to_le_bytes(&value);
+        test_vectors.push(TestVector {
+            operation: "qm31_to_le_bytes".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("value".to_string(), qm31_to_json(&value));
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: {
+                let mut map = HashMap::new();
+                map.insert("round_trip_matches".to_string(), serde_json::Value::Bool(qm31_from_le_bytes(&bytes) == Some(value)));
+                map.insert("matches_into_slice".to_string(), serde_json::Value::Bool(
+                    bytes[..] == *M31::into_slice(&value.to_m31_array())
+                ));
+                map
+            },
+            output: bytes_to_json(&bytes),
+        });
+    }
+
+    // Any out-of-range limb is rejected
+    for (i, limbs) in [[P, 0, 0, 0], [0, 0, 0, 2 * P - 1], [P - 1, P - 1, P - 1, P - 1]].iter().enumerate() {
+        let bytes: [u8; 16] = std::array::from_fn(|j| limbs[j / 4].to_le_bytes()[j % 4]);
+        test_vectors.push(TestVector {
+            operation: "qm31_from_le_bytes".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("bytes".to_string(), bytes_to_json(&bytes));
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: HashMap::new(),
+            output: qm31_from_le_bytes(&bytes).map_or(serde_json::Value::Null, |value| qm31_to_json(&value)),
+        });
+    }
+
+    // Length-prefixed lists: a round trip, then truncated, overlong and out-of-range buffers
+    let values: Vec<SecureField> = (0..5).map(|_| rng.gen()).collect();
+    let bytes = secure_felts_to_bytes(&values);
+    let mut out_of_range = bytes.clone();
+    out_of_range[4..8].copy_from_slice(&P.to_le_bytes());
+    let cases = [
+        ("round_trip", bytes.clone()),
+        ("truncated", bytes[..bytes.len() - 1].to_vec()),
+        ("missing_prefix", bytes[..3].to_vec()),
+        ("trailing_bytes", [&bytes[..], &[0]].concat()),
+        ("out_of_range", out_of_range),
+    ];
+    for (case, buffer) in cases {
+        let decoded = secure_felts_from_bytes(&buffer);
+        test_vectors.push(TestVector {
+            operation: "secure_felts_from_bytes".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("case".to_string(), serde_json::Value::String(case.to_string()));
+                map.insert("bytes".to_string(), bytes_to_json(&buffer));
+                map
+            },
+            intermediates: {
+                let mut map = HashMap::new();
+                map.insert("matches_input".to_string(), serde_json::Value::Bool(decoded.as_ref() == Ok(&values)));
+                map
+            },
+            output: match decoded {
+                Ok(decoded) => serde_json::Value::Array(decoded.iter().map(qm31_to_json).collect()),
+                Err(error) => serde_json::json!({ "error": error }),
+            },
+        });
+    }
+}
+
+fn generate_cm31_byte_vectors(test_vectors: &mut Vec<TestVector>) {
+    let mut rng = SmallRng::seed_from_u64(6);
+
+    // Round trips of random elements
+    for i in 0..10 {
+        let value: CM31 = rng.gen();
+        let bytes = cm31_to_le_bytes(&value);
+        let decoded = cm31_from_le_bytes(&bytes);
+        test_vectors.push(TestVector {
+            operation: "to_le_bytes".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("real".to_string(), serde_json::Value::Number(value.0.0.into()));
+                map.insert("imag".to_string(), serde_json::Value::Number(value.1.0.into()));
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: {
+                let mut map = HashMap::new();
+                map.insert("round_trip_matches".to_string(), serde_json::Value::Bool(decoded == Some(value)));
+                map
+            },
+            output: serde_json::Value::Array(bytes.iter().map(|&b| serde_json::Value::Number(b.into())).collect()),
+        });
+    }
+
+    // Out-of-range limbs are rejected
+    let limb_pairs = vec![(P, 0), (0, P), (P - 1, P - 1), (u32::MAX, 0)];
+    for (i, &(real, imag)) in limb_pairs.iter().enumerate() {
+        let mut bytes = [0u8; 8];
+        bytes[..4].copy_from_slice(&real.to_le_bytes());
+        bytes[4..].copy_from_slice(&imag.to_le_bytes());
+        let decoded = cm31_from_le_bytes(&bytes);
+        test_vectors.push(TestVector {
+            operation: "from_le_bytes".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("bytes".to_string(), serde_json::Value::Array(bytes.iter().map(|&b| serde_json::Value::Number(b.into())).collect()));
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: HashMap::new(),
+            output: match decoded {
+                Some(value) => {
+                    let mut map = HashMap::new();
+                    map.insert("real", serde_json::Value::Number(value.0.0.into()));
+                    map.insert("imag", serde_json::Value::Number(value.1.0.into()));
+                    serde_json::Value::Object(map.into_iter().map(|(k, v)| (k.to_string(), v)).collect())
+                }
+                None => serde_json::Value::Null,
+            },
+        });
+    }
+}
+
 fn generate_qm31_vectors(test_vectors: &mut Vec<TestVector>) {
     let mut rng = SmallRng::seed_from_u64(2);
+
+    // Generate test vectors for multiplicative order checks
+    generate_qm31_order_vectors(test_vectors);
+
+    // Generate test vectors for reducing unreduced coordinates
+    generate_qm31_unreduced_coords_vectors(test_vectors);
+
+    // Generate test vectors for little-endian byte encoding
+    generate_qm31_byte_vectors(test_vectors);
     
     // Generate 30 random test cases for basic operations
     for i in 0..30 {
@@ -930,6 +1918,15 @@ fn generate_qm31_vectors(test_vectors: &mut Vec<TestVector>) {
         });
     }
     
+    // Test checked inverse with its verification product
+    generate_qm31_inverse_checked_vectors(test_vectors);
+
+    // Test the canonical string round trip
+    generate_qm31_canonical_string_vectors(test_vectors);
+
+    // Test compound assignment with an M31 right-hand side
+    generate_m31_rhs_assign_vectors(test_vectors);
+
     // Test mul_cm31
     let qm31_val = QM31::from_u32_unchecked(1, 2, 3, 4);
     let cm31_val = CM31::from_u32_unchecked(5, 6);
@@ -1121,6 +2118,44 @@ fn generate_secure_column_vectors(test_vectors: &mut Vec<TestVector>) {
         ),
     });
     
+    // Test bit reversal of a SIMD secure column
+    generate_secure_column_bit_reverse_vectors(test_vectors);
+
+    // Test SIMD secure columns against the CPU ones
+    generate_secure_column_simd_parity_vectors(test_vectors);
+
+    // Test the coordinate layout: element i is rebuilt from the i-th entry of each coordinate slice
+    let coordinate_slices = secure_column_coordinate_slices(&column);
+    let reconstructed: Vec<SecureField> = (0..column.len())
+        .map(|i| QM31::from_m31_array(std::array::from_fn(|c| coordinate_slices[c][i])))
+        .collect();
+    test_vectors.push(TestVector {
+        operation: "as_coordinate_slices".to_string(),
+        inputs: {
+            let mut map = HashMap::new();
+            map.insert("column_values".to_string(), serde_json::Value::Array(
+                test_values.iter().map(qm31_to_json).collect()
+            ));
+            map
+        },
+        intermediates: {
+            let mut map = HashMap::new();
+            map.insert("reconstruction_matches_to_vec".to_string(), serde_json::Value::Bool(reconstructed == column.to_vec()));
+            map
+        },
+        output: serde_json::Value::Array(
+            coordinate_slices.iter().map(|slice| serde_json::Value::Array(
+                slice.iter().map(|v| serde_json::Value::Number(v.0.into())).collect()
+            )).collect()
+        ),
+    });
+
+    // Test element-wise multiplication of two secure columns
+    generate_secure_column_mul_elementwise_vectors(test_vectors);
+
+    // Test the multiply-accumulate used to build composition polynomials
+    generate_secure_column_mul_add_assign_vectors(test_vectors);
+
     // Test FromIterator
     let from_iter_column: SecureColumnByCoords<CpuBackend> = test_values.iter().cloned().collect();
     let from_iter_vec = from_iter_column.to_vec();
@@ -1149,4 +2184,500 @@ fn generate_secure_column_vectors(test_vectors: &mut Vec<TestVector>) {
             ])).collect()
         ),
     });
-} 
\ No newline at end of file
+} 
+// Helper function to convert a QM31 to its four M31 limbs as JSON
+fn qm31_to_json(value: &QM31) -> serde_json::Value {
+    serde_json::Value::Array(
+        value.to_m31_array().iter().map(|limb| serde_json::Value::Number(limb.0.into())).collect()
+    )
+}
+
+// Bit-reverses a SIMD secure column. The four coordinate columns are permuted by the same
+// bit-reversal permutation, so every element keeps its coordinates together.
+fn bit_reverse_secure_column(column: &mut SecureColumnByCoords<SimdBackend>) {
+    for coordinate_column in column.columns.iter_mut() {
+        <SimdBackend as ColumnOps<BaseField>>::bit_reverse_column(coordinate_column);
+    }
+}
+
+fn generate_secure_column_bit_reverse_vectors(test_vectors: &mut Vec<TestVector>) {
+    let mut rng = SmallRng::seed_from_u64(5);
+
+    // The SIMD bit_reverse_column falls back to the CPU permutation below 2^MIN_LOG_SIZE packed
+    // vectors, i.e. 2^14 elements: log sizes 4, 6 and 8 take the fallback, 14 the vectorized path.
+    for (i, log_size) in [4u32, 6, 8, 14].into_iter().enumerate() {
+        let values: Vec<SecureField> = (0..1 << log_size).map(|_| rng.gen()).collect();
+        let mut column: SecureColumnByCoords<SimdBackend> = values.iter().cloned().collect();
+        bit_reverse_secure_column(&mut column);
+        let result = column.to_vec();
+
+        // Reference: bit-reverse the interleaved QM31 vector directly.
+        let mut expected = values.clone();
+        bit_reverse(&mut expected);
+
+        test_vectors.push(TestVector {
+            operation: "bit_reverse".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("log_size".to_string(), serde_json::Value::Number(log_size.into()));
+                map.insert("values".to_string(), serde_json::Value::Array(
+                    values.iter().map(qm31_to_json).collect()
+                ));
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: {
+                let mut map = HashMap::new();
+                map.insert("matches_reference".to_string(), serde_json::Value::Bool(result == expected));
+                map
+            },
+            output: serde_json::Value::Array(result.iter().map(qm31_to_json).collect()),
+        });
+    }
+}
+
+// SecureColumnByCoords is generic over the backend, so zeros, set, at, to_vec and FromIterator
+// already work on SimdBackend, where set and at address a lane inside each coordinate column's
+// packed words. Lengths that are not a multiple of N_LANES exercise the padded last word.
+fn generate_secure_column_simd_parity_vectors(test_vectors: &mut Vec<TestVector>) {
+    let mut rng = SmallRng::seed_from_u64(26);
+
+    for (i, len) in [1usize, 5, 16, 37].into_iter().enumerate() {
+        let values: Vec<SecureField> = (0..len).map(|_| rng.gen()).collect();
+        let mut cpu = SecureColumnByCoords::<CpuBackend>::zeros(len);
+        let mut simd = SecureColumnByCoords::<SimdBackend>::zeros(len);
+        for (index, &value) in values.iter().enumerate() {
+            cpu.set(index, value);
+            simd.set(index, value);
+        }
+        let collected: SecureColumnByCoords<SimdBackend> = values.iter().cloned().collect();
+
+        test_vectors.push(TestVector {
+            operation: "simd_secure_column".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("values".to_string(), serde_json::Value::Array(values.iter().map(qm31_to_json).collect()));
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: {
+                let mut map = HashMap::new();
+                map.insert("len_matches".to_string(), serde_json::Value::Bool(simd.len() == cpu.len()));
+                map.insert("at_matches".to_string(), serde_json::Value::Bool((0..len).all(|index| simd.at(index) == cpu.at(index))));
+                map.insert("from_iter_matches".to_string(), serde_json::Value::Bool(collected.to_vec() == cpu.to_vec()));
+                map
+            },
+            output: serde_json::Value::Array(simd.to_vec().iter().map(qm31_to_json).collect()),
+        });
+    }
+}
+
+// Checks that `value` has multiplicative order exactly `order`, given the distinct prime factors
+// of `order`: value^order == 1 and value^(order / p) != 1 for every prime factor p.
+fn qm31_has_order(value: &QM31, order: u128, prime_factors: &[u128]) -> bool {
+    value.pow(order).is_one() && prime_factors.iter().all(|&p| !value.pow(order / p).is_one())
+}
+
+fn generate_qm31_order_vectors(test_vectors: &mut Vec<TestVector>) {
+    let minus_one = -QM31::one();
+    let imaginary_unit = QM31::from_u32_unchecked(0, 1, 0, 0);
+
+    // (value, claimed order, prime factors of the claimed order)
+    let test_cases: Vec<(QM31, u128, Vec<u128>)> = vec![
+        (QM31::one(), 1, vec![]),
+        (minus_one, 2, vec![2]),
+        (imaginary_unit, 4, vec![2]),
+        // Wrong claims: -1 does not have order 4, and i does not have order 2.
+        (minus_one, 4, vec![2]),
+        (imaginary_unit, 2, vec![2]),
+    ];
+    for (i, (value, order, prime_factors)) in test_cases.iter().enumerate() {
+        test_vectors.push(TestVector {
+            operation: "has_order".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("value".to_string(), qm31_to_json(value));
+                map.insert("order".to_string(), serde_json::Value::String(order.to_string()));
+                map.insert("prime_factors".to_string(), serde_json::Value::Array(
+                    prime_factors.iter().map(|p| serde_json::Value::String(p.to_string())).collect()
+                ));
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: {
+                let mut map = HashMap::new();
+                map.insert("pow_order".to_string(), qm31_to_json(&value.pow(*order)));
+                map.insert("pow_order_over_factors".to_string(), serde_json::Value::Array(
+                    prime_factors.iter().map(|p| qm31_to_json(&value.pow(order / p))).collect()
+                ));
+                map
+            },
+            output: serde_json::Value::Bool(qm31_has_order(value, *order, prime_factors)),
+        });
+    }
+}
+
+// The inverse together with the verification product `inverse * value`, which is one for a
+// correct inverse. Returns None for zero, which has no inverse.
+fn qm31_inverse_checked(value: &QM31) -> Option<(QM31, QM31)> {
+    if value.is_zero() {
+        return None;
+    }
+    let inverse = value.inverse();
+    Some((inverse, inverse * *value))
+}
+
+fn generate_qm31_inverse_checked_vectors(test_vectors: &mut Vec<TestVector>) {
+    let values = vec![
+        QM31::zero(),
+        QM31::one(),
+        QM31::from_u32_unchecked(1, 2, 3, 4),
+        QM31::from_u32_unchecked(P - 1, 0, 0, 1),
+    ];
+    for (i, value) in values.iter().enumerate() {
+        let checked = qm31_inverse_checked(value);
+        test_vectors.push(TestVector {
+            operation: "inverse_checked".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("value".to_string(), qm31_to_json(value));
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: {
+                let mut map = HashMap::new();
+                if let Some((_, verification_product)) = checked {
+                    map.insert("verification_product".to_string(), qm31_to_json(&verification_product));
+                    map.insert("product_is_one".to_string(), serde_json::Value::Bool(verification_product.is_one()));
+                }
+                map
+            },
+            output: match checked {
+                Some((inverse, _)) => qm31_to_json(&inverse),
+                None => serde_json::Value::Null,
+            },
+        });
+    }
+}
+
+// Compact canonical string of a QM31: its four limbs in decimal, comma-separated, e.g. "1,2,3,4".
+fn qm31_to_canonical_string(value: &QM31) -> String {
+    value.to_m31_array().map(|limb| limb.0.to_string()).join(",")
+}
+
+// Parses the canonical string form. Stricter than qm31_from_json so every QM31 has exactly one
+// string: four limbs below P, no whitespace, signs or leading zeros.
+fn qm31_from_canonical_string(s: &str) -> Result<QM31, String> {
+    let parts: Vec<&str> = s.split(',').collect();
+    if parts.len() != SECURE_EXTENSION_DEGREE {
+        return Err(format!("expected {} limbs, found {}", SECURE_EXTENSION_DEGREE, parts.len()));
+    }
+    let mut limbs = [M31::zero(); SECURE_EXTENSION_DEGREE];
+    for (limb, part) in limbs.iter_mut().zip(parts) {
+        if part.is_empty() || !part.bytes().all(|b| b.is_ascii_digit()) || (part.len() > 1 && part.starts_with('0')) {
+            return Err(format!("invalid limb {:?}", part));
+        }
+        let value = part.parse::<u32>().map_err(|e| format!("invalid limb {:?}: {}", part, e))?;
+        if value >= P {
+            return Err(format!("limb {} is not below P", value));
+        }
+        *limb = M31::from_u32_unchecked(value);
+    }
+    Ok(QM31::from_m31_array(limbs))
+}
+
+fn generate_qm31_canonical_string_vectors(test_vectors: &mut Vec<TestVector>) {
+    let mut rng = SmallRng::seed_from_u64(16);
+    let mut values = vec![QM31::zero(), QM31::one(), QM31::from_u32_unchecked(P - 1, 0, 1, P - 1)];
+    values.extend((0..5).map(|_| {
+        QM31::from_u32_unchecked(rng.gen_range(0..P), rng.gen_range(0..P), rng.gen_range(0..P), rng.gen_range(0..P))
+    }));
+    for (i, value) in values.iter().enumerate() {
+        let string = qm31_to_canonical_string(value);
+        test_vectors.push(TestVector {
+            operation: "qm31_canonical_string".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("value".to_string(), qm31_to_json(value));
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: {
+                let mut map = HashMap::new();
+                map.insert("round_trips".to_string(), serde_json::Value::Bool(qm31_from_canonical_string(&string) == Ok(*value)));
+                map
+            },
+            output: serde_json::Value::String(string),
+        });
+    }
+
+    let malformed_inputs = [
+        "", "1,2,3", "1,2,3,4,5", "1,2,3,", " 1,2,3,4", "1, 2,3,4", "+1,2,3,4", "-1,2,3,4", "01,2,3,4", "a,2,3,4",
+        "2147483647,0,0,0", "4294967296,0,0,0",
+    ];
+    for (i, input) in malformed_inputs.iter().enumerate() {
+        let result = qm31_from_canonical_string(input);
+        test_vectors.push(TestVector {
+            operation: "qm31_canonical_string_malformed".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("string".to_string(), serde_json::Value::String(input.to_string()));
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: {
+                let mut map = HashMap::new();
+                map.insert("error".to_string(), serde_json::Value::String(result.clone().err().unwrap_or_default()));
+                map
+            },
+            output: serde_json::Value::Bool(result.is_err()),
+        });
+    }
+}
+
+// CM31 and QM31 already implement AddAssign<M31> and MulAssign<M31>, embedding the M31 as a
+// base-field element. These vectors pin them against the functional forms with the embedding
+// spelled out.
+fn generate_m31_rhs_assign_vectors(test_vectors: &mut Vec<TestVector>) {
+    let mut rng = SmallRng::seed_from_u64(15);
+    for i in 0..5 {
+        let cm31_val: CM31 = rng.gen();
+        let qm31_val: QM31 = rng.gen();
+        let m31_val: M31 = rng.gen();
+
+        let mut cm31_sum = cm31_val;
+        cm31_sum += m31_val;
+        let mut cm31_product = cm31_val;
+        cm31_product *= m31_val;
+        let mut qm31_sum = qm31_val;
+        qm31_sum += m31_val;
+        let mut qm31_product = qm31_val;
+        qm31_product *= m31_val;
+
+        test_vectors.push(TestVector {
+            operation: "assign_m31_rhs".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("cm31".to_string(), serde_json::json!([cm31_val.0.0, cm31_val.1.0]));
+                map.insert("qm31".to_string(), qm31_to_json(&qm31_val));
+                map.insert("m31".to_string(), serde_json::Value::Number(m31_val.0.into()));
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: {
+                let mut map = HashMap::new();
+                map.insert("cm31_add_matches".to_string(), serde_json::Value::Bool(cm31_sum == cm31_val + CM31::from(m31_val)));
+                map.insert("cm31_mul_matches".to_string(), serde_json::Value::Bool(cm31_product == cm31_val * CM31::from(m31_val)));
+                map.insert("qm31_add_matches".to_string(), serde_json::Value::Bool(qm31_sum == qm31_val + QM31::from(m31_val)));
+                map.insert("qm31_mul_matches".to_string(), serde_json::Value::Bool(qm31_product == qm31_val * QM31::from(m31_val)));
+                map
+            },
+            output: serde_json::json!({
+                "cm31_add_assign": [cm31_sum.0.0, cm31_sum.1.0],
+                "cm31_mul_assign": [cm31_product.0.0, cm31_product.1.0],
+                "qm31_add_assign": qm31_to_json(&qm31_sum),
+                "qm31_mul_assign": qm31_to_json(&qm31_product)
+            }),
+        });
+    }
+}
+
+// Reduces four unreduced coordinates (e.g. coordinate-wise products, as in multiplying by an M31
+// scalar) into a QM31 with M31::reduce. Each coordinate must be below 2^62, the range `reduce`
+// accepts; a product of two reduced limbs always is.
+fn qm31_from_unreduced_coords(coords: [u64; SECURE_EXTENSION_DEGREE]) -> QM31 {
+    QM31::from_m31_array(coords.map(M31::reduce))
+}
+
+fn generate_qm31_unreduced_coords_vectors(test_vectors: &mut Vec<TestVector>) {
+    let mut rng = SmallRng::seed_from_u64(11);
+    let mut cases: Vec<(QM31, M31)> = (0..10).map(|_| (rng.gen(), rng.gen())).collect();
+    cases.push((QM31::from_u32_unchecked(P - 1, P - 1, P - 1, P - 1), M31::from(P - 1)));
+    cases.push((QM31::one(), M31::zero()));
+
+    for (i, &(value, scalar)) in cases.iter().enumerate() {
+        let coords = value.to_m31_array().map(|limb| m31_mul_wide(limb, scalar));
+        let result = qm31_from_unreduced_coords(coords);
+        test_vectors.push(TestVector {
+            operation: "from_unreduced_coords".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("coords".to_string(), serde_json::Value::Array(
+                    coords.iter().map(|c| serde_json::Value::String(c.to_string())).collect()
+                ));
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: {
+                let mut map = HashMap::new();
+                map.insert("value".to_string(), qm31_to_json(&value));
+                map.insert("scalar".to_string(), serde_json::Value::Number(scalar.0.into()));
+                map.insert("matches_scalar_mul".to_string(), serde_json::Value::Bool(result == value * scalar));
+                map
+            },
+            output: qm31_to_json(&result),
+        });
+    }
+}
+
+// SecureColumnByCoords stores one base-field column per coordinate. Element i is
+// QM31::from_m31_array([columns[0][i], columns[1][i], columns[2][i], columns[3][i]]): columns 0 and 1
+// are the real and imaginary parts of the first CM31, columns 2 and 3 those of the second (the
+// coefficient of u). Returns read-only views of the four coordinate columns in that order.
+fn secure_column_coordinate_slices(column: &SecureColumnByCoords<CpuBackend>) -> [&[M31]; SECURE_EXTENSION_DEGREE] {
+    std::array::from_fn(|c| column.columns[c].as_slice())
+}
+
+fn generate_json_parsing_vectors(test_vectors: &mut Vec<TestVector>) {
+    let m31_inputs = vec![
+        serde_json::json!(P - 1),
+        serde_json::json!((P - 1).to_string()),
+        serde_json::json!(P),
+        serde_json::json!((P as u64 * 3 + 2).to_string()),
+    ];
+    for (i, input) in m31_inputs.iter().enumerate() {
+        let parsed = m31_from_json(input).unwrap();
+        test_vectors.push(TestVector {
+            operation: "m31_from_json".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("json".to_string(), input.clone());
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: HashMap::new(),
+            output: serde_json::Value::Number(parsed.0.into()),
+        });
+    }
+
+    let qm31_inputs = vec![
+        serde_json::json!([1, 2, 3, 4]),
+        serde_json::json!(["1", 2, "3", 4]),
+        serde_json::json!([(P - 1).to_string(), P - 1, P.to_string(), 0]),
+    ];
+    for (i, input) in qm31_inputs.iter().enumerate() {
+        let parsed = qm31_from_json(input).unwrap();
+        test_vectors.push(TestVector {
+            operation: "qm31_from_json".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("json".to_string(), input.clone());
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: HashMap::new(),
+            output: qm31_to_json(&parsed),
+        });
+    }
+
+    // Malformed limbs are rejected rather than silently read as zero.
+    let malformed_inputs = vec![serde_json::json!("not a number"), serde_json::json!(-1), serde_json::json!([1, 2, 3])];
+    for (i, input) in malformed_inputs.iter().enumerate() {
+        let rejected = m31_from_json(input).is_err() && qm31_from_json(input).is_err();
+        test_vectors.push(TestVector {
+            operation: "from_json_malformed".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("json".to_string(), input.clone());
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: HashMap::new(),
+            output: serde_json::Value::Bool(rejected),
+        });
+    }
+}
+
+// Element-wise (Hadamard) product of two secure columns of equal length.
+fn secure_column_mul_elementwise(
+    a: &SecureColumnByCoords<CpuBackend>,
+    b: &SecureColumnByCoords<CpuBackend>,
+) -> SecureColumnByCoords<CpuBackend> {
+    assert_eq!(a.len(), b.len(), "Secure columns must have equal lengths");
+    (0..a.len()).map(|i| a.at(i) * b.at(i)).collect()
+}
+
+fn generate_secure_column_mul_elementwise_vectors(test_vectors: &mut Vec<TestVector>) {
+    let mut rng = SmallRng::seed_from_u64(8);
+
+    for (i, len) in [0usize, 1, 8].into_iter().enumerate() {
+        let a: SecureColumnByCoords<CpuBackend> = (0..len).map(|_| rng.gen::<SecureField>()).collect();
+        let b: SecureColumnByCoords<CpuBackend> = (0..len).map(|_| rng.gen::<SecureField>()).collect();
+        let product = secure_column_mul_elementwise(&a, &b);
+
+        test_vectors.push(TestVector {
+            operation: "mul_elementwise".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("a".to_string(), serde_json::Value::Array(a.to_vec().iter().map(qm31_to_json).collect()));
+                map.insert("b".to_string(), serde_json::Value::Array(b.to_vec().iter().map(qm31_to_json).collect()));
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: {
+                let mut map = HashMap::new();
+                map.insert("matches_scalar_mul".to_string(), serde_json::Value::Bool(
+                    product.len() == len && (0..len).all(|j| product.at(j) == a.at(j) * b.at(j))
+                ));
+                map
+            },
+            output: serde_json::Value::Array(product.to_vec().iter().map(qm31_to_json).collect()),
+        });
+    }
+}
+
+fn generate_secure_column_mul_add_assign_vectors(test_vectors: &mut Vec<TestVector>) {
+    let mut rng = SmallRng::seed_from_u64(12);
+
+    // Scalar reference on small columns
+    for (i, len) in [0usize, 1, 8].into_iter().enumerate() {
+        let coeff = rng.gen::<SecureField>();
+        let initial: SecureColumnByCoords<CpuBackend> = (0..len).map(|_| rng.gen::<SecureField>()).collect();
+        let other: SecureColumnByCoords<CpuBackend> = (0..len).map(|_| rng.gen::<SecureField>()).collect();
+        let mut acc = initial.clone();
+        secure_column_mul_add_assign(&mut acc, coeff, &other);
+
+        test_vectors.push(TestVector {
+            operation: "mul_add_assign".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("acc".to_string(), serde_json::Value::Array(initial.to_vec().iter().map(qm31_to_json).collect()));
+                map.insert("coeff".to_string(), qm31_to_json(&coeff));
+                map.insert("other".to_string(), serde_json::Value::Array(other.to_vec().iter().map(qm31_to_json).collect()));
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: HashMap::new(),
+            output: serde_json::Value::Array(acc.to_vec().iter().map(qm31_to_json).collect()),
+        });
+    }
+
+    // SIMD against the scalar reference on a 2^18 column
+    let log_len = 18u32;
+    let len = 1usize << log_len;
+    let coeff = rng.gen::<SecureField>();
+    let initial: Vec<SecureField> = (0..len).map(|_| rng.gen()).collect();
+    let other: Vec<SecureField> = (0..len).map(|_| rng.gen()).collect();
+
+    let mut expected: SecureColumnByCoords<CpuBackend> = initial.iter().cloned().collect();
+    secure_column_mul_add_assign(&mut expected, coeff, &other.iter().cloned().collect());
+    let mut acc: SecureColumnByCoords<SimdBackend> = initial.into_iter().collect();
+    secure_column_mul_add_assign_simd(&mut acc, coeff, &other.into_iter().collect());
+    let result = acc.to_cpu();
+
+    test_vectors.push(TestVector {
+        operation: "mul_add_assign_simd".to_string(),
+        inputs: {
+            let mut map = HashMap::new();
+            map.insert("log_len".to_string(), serde_json::Value::Number(log_len.into()));
+            map.insert("coeff".to_string(), qm31_to_json(&coeff));
+            map
+        },
+        intermediates: {
+            let mut map = HashMap::new();
+            map.insert("matches_scalar_reference".to_string(), serde_json::Value::Bool(result.to_vec() == expected.to_vec()));
+            map
+        },
+        output: serde_json::Value::Array(result.to_vec()[..4].iter().map(qm31_to_json).collect()),
+    });
+}
@@ -0,0 +1,106 @@
+//! Merkle hashing test vectors.
+//!
+//! `MerkleHasher::hash_node` absorbs base-field values only. Secure-field leaf values are hashed
+//! by flattening each `SecureField` to its four M31 coordinates in `to_m31_array` order
+//! (`[a.0.0, a.0.1, a.1.0, a.1.1]`), value after value. This is the same coordinate order a
+//! `SecureColumnByCoords` commits its four base columns in.
+
+use std::collections::HashMap;
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+use stwo_prover::core::backend::CpuBackend;
+use stwo_prover::core::fields::m31::BaseField;
+use stwo_prover::core::fields::qm31::SecureField;
+use stwo_prover::core::vcs::blake2_merkle::Blake2sMerkleHasher;
+use stwo_prover::core::vcs::ops::MerkleHasher;
+use stwo_prover::core::vcs::prover::MerkleProver;
+
+use crate::merkle_ops::commit_rowmajor;
+use crate::{qm31_to_json, TestVector};
+
+// Hashes a node whose column values are secure-field elements, flattened as described above.
+fn hash_node_secure<H: MerkleHasher>(
+    children_hashes: Option<(H::Hash, H::Hash)>,
+    secure_values: &[SecureField],
+) -> H::Hash {
+    let base_values: Vec<BaseField> = secure_values.iter().flat_map(|value| value.to_m31_array()).collect();
+    H::hash_node(children_hashes, &base_values)
+}
+
+pub(crate) fn generate_merkle_vectors(test_vectors: &mut Vec<TestVector>) {
+    generate_hash_node_secure_vectors(test_vectors);
+    generate_commit_rowmajor_vectors(test_vectors);
+}
+
+fn generate_hash_node_secure_vectors(test_vectors: &mut Vec<TestVector>) {
+    let mut rng = SmallRng::seed_from_u64(13);
+    let left = Blake2sMerkleHasher::hash_node(None, &[BaseField::from(1u32)]);
+    let right = Blake2sMerkleHasher::hash_node(None, &[BaseField::from(2u32)]);
+
+    for (i, (children_hashes, len)) in [(None, 1usize), (None, 3), (Some((left, right)), 2)].into_iter().enumerate() {
+        let secure_values: Vec<SecureField> = (0..len).map(|_| rng.gen()).collect();
+        let hash = hash_node_secure::<Blake2sMerkleHasher>(children_hashes, &secure_values);
+
+        // Flatten by hand, spelling out the coordinate order.
+        let manual: Vec<BaseField> = secure_values
+            .iter()
+            .flat_map(|value| [value.0 .0, value.0 .1, value.1 .0, value.1 .1])
+            .collect();
+        let manual_hash = Blake2sMerkleHasher::hash_node(children_hashes, &manual);
+
+        test_vectors.push(TestVector {
+            operation: "hash_node_secure".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("children_hashes".to_string(), match children_hashes {
+                    Some((left, right)) => serde_json::json!([format!("{:x}", left), format!("{:x}", right)]),
+                    None => serde_json::Value::Null,
+                });
+                map.insert("secure_values".to_string(), serde_json::Value::Array(secure_values.iter().map(qm31_to_json).collect()));
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: {
+                let mut map = HashMap::new();
+                map.insert("flattened".to_string(), serde_json::Value::Array(
+                    manual.iter().map(|limb| serde_json::Value::Number(limb.0.into())).collect()
+                ));
+                map.insert("matches_manual_flattening".to_string(), serde_json::Value::Bool(hash == manual_hash));
+                map
+            },
+            output: serde_json::Value::String(format!("{:x}", hash)),
+        });
+    }
+}
+
+fn generate_commit_rowmajor_vectors(test_vectors: &mut Vec<TestVector>) {
+    let mut rng = SmallRng::seed_from_u64(14);
+
+    for (i, (n_columns, log_n_rows)) in [(1usize, 0u32), (3, 4), (256, 12)].into_iter().enumerate() {
+        let n_rows = 1usize << log_n_rows;
+        let columns: Vec<Vec<BaseField>> = (0..n_columns).map(|_| (0..n_rows).map(|_| rng.gen()).collect()).collect();
+        let rowmajor: Vec<BaseField> = (0..n_rows).flat_map(|r| columns.iter().map(move |column| column[r])).collect();
+
+        let root = commit_rowmajor::<Blake2sMerkleHasher>(&rowmajor, n_columns);
+        let column_major_root =
+            MerkleProver::<CpuBackend, Blake2sMerkleHasher>::commit(columns.iter().collect()).root();
+
+        test_vectors.push(TestVector {
+            operation: "commit_rowmajor".to_string(),
+            inputs: {
+                let mut map = HashMap::new();
+                map.insert("n_columns".to_string(), serde_json::Value::Number(n_columns.into()));
+                map.insert("log_n_rows".to_string(), serde_json::Value::Number(log_n_rows.into()));
+                map.insert("test_case".to_string(), serde_json::Value::Number(i.into()));
+                map
+            },
+            intermediates: {
+                let mut map = HashMap::new();
+                map.insert("column_major_root".to_string(), serde_json::Value::String(format!("{:x}", column_major_root)));
+                map.insert("matches_column_major".to_string(), serde_json::Value::Bool(root == column_major_root));
+                map
+            },
+            output: serde_json::Value::String(format!("{:x}", root)),
+        });
+    }
+}
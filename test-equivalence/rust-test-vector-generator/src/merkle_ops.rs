@@ -0,0 +1,21 @@
+//! Merkle kernels, kept free of the vector plumbing so `benches/commit_rowmajor.rs` can include
+//! this file directly (the generator is a binary crate with no library target).
+
+use stwo_prover::core::fields::m31::BaseField;
+use stwo_prover::core::vcs::ops::MerkleHasher;
+
+// Merkle root of equal-length columns given row-major: row r is
+// values[r * n_columns..(r + 1) * n_columns]. Each leaf hashes one contiguous row, and each node
+// above hashes its two children with no column values, which is how MerkleProver::commit lays out
+// columns of a single size.
+pub(crate) fn commit_rowmajor<H: MerkleHasher>(values: &[BaseField], n_columns: usize) -> H::Hash {
+    assert!(n_columns > 0 && values.len() % n_columns == 0, "values must hold whole rows");
+    let n_rows = values.len() / n_columns;
+    assert!(n_rows.is_power_of_two(), "the number of rows must be a power of two");
+
+    let mut layer: Vec<H::Hash> = values.chunks(n_columns).map(|row| H::hash_node(None, row)).collect();
+    while layer.len() > 1 {
+        layer = layer.chunks(2).map(|pair| H::hash_node(Some((pair[0], pair[1])), &[])).collect();
+    }
+    layer[0]
+}
@@ -0,0 +1,37 @@
+//! Secure-column kernels, kept free of the vector plumbing so `benches/mul_add_assign.rs` can
+//! include this file directly (the generator is a binary crate with no library target).
+
+use stwo_prover::core::backend::simd::qm31::PackedSecureField;
+use stwo_prover::core::backend::simd::SimdBackend;
+use stwo_prover::core::backend::CpuBackend;
+use stwo_prover::core::fields::qm31::SecureField;
+use stwo_prover::core::fields::secure_column::SecureColumnByCoords;
+
+// Accumulates a random-linear-combination term in place: acc[i] += coeff * other[i]. Scalar
+// reference for the SIMD version below.
+pub(crate) fn secure_column_mul_add_assign(
+    acc: &mut SecureColumnByCoords<CpuBackend>,
+    coeff: SecureField,
+    other: &SecureColumnByCoords<CpuBackend>,
+) {
+    assert_eq!(acc.len(), other.len(), "Secure columns must have equal lengths");
+    for i in 0..acc.len() {
+        acc.set(i, acc.at(i) + coeff * other.at(i));
+    }
+}
+
+// SIMD version of secure_column_mul_add_assign, processing one packed vector of N_LANES
+// elements at a time.
+pub(crate) fn secure_column_mul_add_assign_simd(
+    acc: &mut SecureColumnByCoords<SimdBackend>,
+    coeff: SecureField,
+    other: &SecureColumnByCoords<SimdBackend>,
+) {
+    assert_eq!(acc.len(), other.len(), "Secure columns must have equal lengths");
+    let packed_coeff = PackedSecureField::broadcast(coeff);
+    for vec_index in 0..acc.columns[0].data.len() {
+        let value = acc.packed_at(vec_index) + packed_coeff * other.packed_at(vec_index);
+        // SAFETY: vec_index is below the number of packed vectors in every coordinate column.
+        unsafe { acc.set_packed(vec_index, value) };
+    }
+}
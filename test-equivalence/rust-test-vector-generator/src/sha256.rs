@@ -0,0 +1,12 @@
+//! A SHA-256 Fiat-Shamir channel, for verifiers that only have SHA-256 available.
+//!
+//! The channel is a `DigestChannel` over SHA-256, so it follows `Keccak256Channel` (and so
+//! `Blake2sChannel`) exactly, with the same `< 2P` rejection loop for base felts.
+
+use sha2::Sha256;
+
+use crate::digest_channel::{DigestChannel, DIGEST_CHANNEL_BYTES_PER_HASH};
+
+pub(crate) const SHA256_BYTES_PER_HASH: usize = DIGEST_CHANNEL_BYTES_PER_HASH;
+
+pub(crate) type Sha256Channel = DigestChannel<Sha256>;
@@ -1,160 +1,2138 @@
 use std::fs;
 use serde_json::{json, Value};
 use clap::{Arg, Command};
+use num_traits::Zero;
+use stwo_prover::constraint_framework::{EvalAtRow, FrameworkComponent, FrameworkEval, TraceLocationAllocator};
 use stwo_prover::core::{
+    air::{accumulation::PointEvaluationAccumulator, Component},
     backend::{
         simd::{
+            circle::CircleDomainBitRevIterator,
             column::BaseColumn,
-            m31::{LOG_N_LANES, N_LANES},
+            m31::{PackedM31, LOG_N_LANES, N_LANES},
             SimdBackend,
         },
-        Column,
+        BackendForChannel, Col, Column, ColumnOps, CpuBackend,
     },
-    channel::{Blake2sChannel, Channel},
-    fields::m31::M31,
+    channel::{logging_channel::LoggingMerkleChannel, Blake2sChannel, Channel, MerkleChannel},
+    circle::{CirclePoint, CirclePointIndex, Coset, M31_CIRCLE_GEN, M31_CIRCLE_LOG_ORDER},
+    fields::{m31::{M31, P}, qm31::SecureField, secure_column::SECURE_EXTENSION_DEGREE},
+    fri::FriConfig,
     pcs::{PcsConfig, prover::CommitmentSchemeProver, CommitmentSchemeVerifier},
     poly::{
-        circle::{CanonicCoset, CircleEvaluation},
+        circle::{CanonicCoset, CircleDomain, CircleEvaluation, CirclePoly, CosetSubEvaluation, PolyOps},
+        twiddles::TwiddleTree,
         BitReversedOrder,
     },
-    prover::{prove, verify},
-    vcs::blake2_merkle::Blake2sMerkleChannel,
+    prover::{prove, verify, StarkProof, VerificationError},
+    utils::{bit_reverse, bit_reverse_index},
+    vcs::{
+        blake2_merkle::{Blake2sMerkleChannel, Blake2sMerkleHasher},
+        ops::MerkleHasher,
+        poseidon252_merkle::Poseidon252MerkleChannel,
+        prover::MerkleProver,
+    },
     ColumnVec,
 };
 
-// Configuration for table values
-#[derive(Debug, Clone)]
-struct TableConfig {
-    col1_val0: u32,
-    col1_val1: u32,
-    col2_val0: u32,
-    col2_val1: u32,
+// Configuration for table values
+#[derive(Debug, Clone)]
+struct TableConfig {
+    col1_val0: u32,
+    col1_val1: u32,
+    col2_val0: u32,
+    col2_val1: u32,
+}
+
+impl Default for TableConfig {
+    fn default() -> Self {
+        Self {
+            col1_val0: 1,
+            col1_val1: 7,
+            col2_val0: 5,
+            col2_val1: 11,
+        }
+    }
+}
+
+// Helper function to create the standard table with configurable values
+fn create_standard_table(config: &TableConfig) -> (BaseColumn, BaseColumn) {
+    let num_rows = N_LANES;
+    
+    let mut col_1 = BaseColumn::zeros(num_rows);
+    col_1.set(0, M31::from(config.col1_val0));
+    col_1.set(1, M31::from(config.col1_val1));
+
+    let mut col_2 = BaseColumn::zeros(num_rows);
+    col_2.set(0, M31::from(config.col2_val0));
+    col_2.set(1, M31::from(config.col2_val1));
+    
+    (col_1, col_2)
+}
+
+const CONSTRAINT_EVAL_BLOWUP_FACTOR: u32 = 1;
+
+// Security level PcsConfig::default() is meant to provide: 5 pow bits plus 3 queries at log
+// blowup 1. The audit vectors flag any change to the defaults that moves it.
+const DEFAULT_PCS_SECURITY_BITS: u32 = 8;
+
+// Helper function building a PcsConfig that meets at least `target_bits` of security. Keeps the
+// default pow bits, blowup and last layer bound, and sets the number of FRI queries to the fewest
+// that cover the remaining bits.
+fn pcs_config_for_security_bits(target_bits: u32) -> PcsConfig {
+    let default = PcsConfig::default();
+    let log_blowup_factor = default.fri_config.log_blowup_factor;
+    let n_queries = target_bits.saturating_sub(default.pow_bits).div_ceil(log_blowup_factor).max(1);
+    PcsConfig {
+        pow_bits: default.pow_bits,
+        fri_config: FriConfig::new(
+            default.fri_config.log_last_layer_degree_bound,
+            log_blowup_factor,
+            n_queries as usize,
+        ),
+    }
+}
+
+// Error returned when a coset cannot be built
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CosetError {
+    // The requested log size exceeds the circle group's log order
+    LogSizeTooLarge(u32),
+}
+
+impl std::fmt::Display for CosetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CosetError::LogSizeTooLarge(log_size) => write!(
+                f,
+                "coset log size {} exceeds the circle's maximum log order {}",
+                log_size, M31_CIRCLE_LOG_ORDER
+            ),
+        }
+    }
+}
+
+// Helper function building a coset, rejecting log sizes the circle group cannot hold. The step
+// is the subgroup generator of order 2^log_size, so any initial index is compatible.
+fn try_coset(initial_index: CirclePointIndex, log_size: u32) -> Result<Coset, CosetError> {
+    if log_size > M31_CIRCLE_LOG_ORDER {
+        return Err(CosetError::LogSizeTooLarge(log_size));
+    }
+    Ok(Coset::new(initial_index, log_size))
+}
+
+// Helper function normalizing a domain to one representative of its point set. A domain is its
+// half coset i0 + <s> together with the conjugate -i0 + <s>, which only depend on i0 mod s, so
+// the representative starts the half coset at the smaller of i0 and -i0 mod s. Domains built from
+// a shifted or conjugated half coset canonicalize to the same value. The commitment scheme commits
+// polynomials, evaluated on its own canonic LDE domain, so compare domains through this rather
+// than with ==, which also compares how they were built.
+fn canonicalize_domain(domain: &CircleDomain) -> CircleDomain {
+    let half_coset = domain.half_coset;
+    let step_size = 1usize << (M31_CIRCLE_LOG_ORDER - half_coset.log_size);
+    let index = half_coset.initial_index.0 % step_size;
+    let conjugate_index = (step_size - index) % step_size;
+    CircleDomain::new(Coset::new(CirclePointIndex(index.min(conjugate_index)), half_coset.log_size))
+}
+
+// Log size of the domain twiddles are precomputed on: the trace size plus both blowups. Panics if
+// it reaches the circle group's order: a canonic coset of log size n is built from the subgroup of
+// order 2^(n + 1), so CanonicCoset::new(31) would already fail inside stwo without the sizes.
+fn twiddle_domain_log_size(log_num_rows: u32, fri_log_blowup_factor: u32) -> u32 {
+    let log_size = log_num_rows + CONSTRAINT_EVAL_BLOWUP_FACTOR + fri_log_blowup_factor;
+    assert!(
+        log_size < M31_CIRCLE_LOG_ORDER,
+        "twiddle domain log size {} ({} rows + {} constraint blowup + {} FRI blowup) must be below the circle's log order {}",
+        log_size,
+        log_num_rows,
+        CONSTRAINT_EVAL_BLOWUP_FACTOR,
+        fri_log_blowup_factor,
+        M31_CIRCLE_LOG_ORDER
+    );
+    log_size
+}
+
+// Helper function running f and catching a panic without printing it. The default hook writes
+// every panic message to stderr, which is noise for panics the vectors expect; the previous hook
+// is restored afterwards.
+fn catch_unwind_silent<R>(f: impl FnOnce() -> R) -> std::thread::Result<R> {
+    let hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(|_| {}));
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(f));
+    std::panic::set_hook(hook);
+    result
+}
+
+// Helper function reading a caught panic's message: panic! with format arguments carries a String,
+// a literal message a &'static str.
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    payload
+        .downcast_ref::<String>()
+        .cloned()
+        .or_else(|| payload.downcast_ref::<&str>().map(|message| message.to_string()))
+        .unwrap_or_default()
+}
+
+// The example AIR: col_1 * col_2 + col_1 - col_3 = 0
+struct MulAddEval {
+    log_size: u32,
+}
+
+impl FrameworkEval for MulAddEval {
+    fn log_size(&self) -> u32 {
+        self.log_size
+    }
+
+    fn max_constraint_log_degree_bound(&self) -> u32 {
+        self.log_size + CONSTRAINT_EVAL_BLOWUP_FACTOR
+    }
+
+    fn evaluate<E: EvalAtRow>(&self, mut eval: E) -> E {
+        let col_1 = eval.next_trace_mask();
+        let col_2 = eval.next_trace_mask();
+        let col_3 = eval.next_trace_mask();
+        eval.add_constraint(col_1.clone() * col_2.clone() + col_1.clone() - col_3.clone());
+        eval
+    }
+}
+
+// A second small AIR: col_a * col_a - col_b = 0
+struct SquareEval {
+    log_size: u32,
+}
+
+impl FrameworkEval for SquareEval {
+    fn log_size(&self) -> u32 {
+        self.log_size
+    }
+
+    fn max_constraint_log_degree_bound(&self) -> u32 {
+        self.log_size + CONSTRAINT_EVAL_BLOWUP_FACTOR
+    }
+
+    fn evaluate<E: EvalAtRow>(&self, mut eval: E) -> E {
+        let col_a = eval.next_trace_mask();
+        let col_b = eval.next_trace_mask();
+        eval.add_constraint(col_a.clone() * col_a.clone() - col_b.clone());
+        eval
+    }
+}
+
+// A component with no trace columns and no constraints
+struct EmptyEval {
+    log_size: u32,
+}
+
+impl FrameworkEval for EmptyEval {
+    fn log_size(&self) -> u32 {
+        self.log_size
+    }
+
+    fn max_constraint_log_degree_bound(&self) -> u32 {
+        self.log_size + CONSTRAINT_EVAL_BLOWUP_FACTOR
+    }
+
+    fn evaluate<E: EvalAtRow>(&self, eval: E) -> E {
+        eval
+    }
+}
+
+// Runs two evals over the same trace as one component. `evaluate` runs both in sequence on the
+// same EvalAtRow, so their constraints are concatenated and the second eval's trace columns
+// follow the first's. Nest it to combine more than two evals.
+struct CombinedEval<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A: FrameworkEval, B: FrameworkEval> CombinedEval<A, B> {
+    fn new(first: A, second: B) -> Self {
+        assert_eq!(
+            first.log_size(),
+            second.log_size(),
+            "Combined evals must share the trace log size"
+        );
+        Self { first, second }
+    }
+}
+
+impl<A: FrameworkEval, B: FrameworkEval> FrameworkEval for CombinedEval<A, B> {
+    fn log_size(&self) -> u32 {
+        self.first.log_size()
+    }
+
+    fn max_constraint_log_degree_bound(&self) -> u32 {
+        self.first
+            .max_constraint_log_degree_bound()
+            .max(self.second.max_constraint_log_degree_bound())
+    }
+
+    fn evaluate<E: EvalAtRow>(&self, eval: E) -> E {
+        self.second.evaluate(self.first.evaluate(eval))
+    }
+}
+
+// Helper function wrapping columns into a trace over the canonic domain of `log_size`
+fn columns_to_trace(columns: Vec<BaseColumn>, log_size: u32) -> ColumnVec<CircleEvaluation<SimdBackend, M31, BitReversedOrder>> {
+    let domain = CanonicCoset::new(log_size).circle_domain();
+    columns
+        .into_iter()
+        .map(|col| CircleEvaluation::new(domain, col))
+        .collect()
+}
+
+// Builds a trace from column literals, e.g. `trace!(log = 4; [1, 7], [5, 11])`. Each bracketed
+// list holds one column's values from row 0 down and is zero-padded to 2^log rows.
+macro_rules! trace {
+    (log = $log_size:expr; $([$($value:expr),* $(,)?]),+ $(,)?) => {{
+        let log_size: u32 = $log_size;
+        let columns = vec![$({
+            let values: Vec<u32> = vec![$($value),*];
+            assert!(values.len() <= 1 << log_size, "{} values do not fit in 2^{} rows", values.len(), log_size);
+            let mut column = BaseColumn::zeros(1 << log_size);
+            for (row, value) in values.into_iter().enumerate() {
+                column.set(row, M31::from(value));
+            }
+            column
+        }),+];
+        columns_to_trace(columns, log_size)
+    }};
+}
+
+// Helper function evaluating a polynomial back on a domain of its own size, without blowup, e.g.
+// to check an interpolation
+fn evaluate_same_size(
+    poly: &CirclePoly<SimdBackend>,
+    domain: CircleDomain,
+    twiddles: &TwiddleTree<SimdBackend>,
+) -> CircleEvaluation<SimdBackend, M31, BitReversedOrder> {
+    assert_eq!(domain.log_size(), poly.log_size(), "domain size must equal the coefficient count");
+    poly.evaluate_with_twiddles(domain, twiddles)
+}
+
+// Helper function evaluating a column at a single point, e.g. an out-of-domain sample. This still
+// interpolates the whole column, O(n log n), but reuses the caller's twiddles so repeated calls
+// skip precomputation. To sample one column at many points, interpolate once and call
+// CirclePoly::eval_at_point instead.
+fn evaluation_eval_at_point(
+    evaluation: &CircleEvaluation<SimdBackend, M31, BitReversedOrder>,
+    point: CirclePoint<SecureField>,
+    twiddles: &TwiddleTree<SimdBackend>,
+) -> SecureField {
+    evaluation.clone().interpolate_with_twiddles(twiddles).eval_at_point(point)
+}
+
+// Helper function computing the blown-up evaluations the commitment scheme hashes into Merkle
+// leaves, so they can be reused without recomputing the LDE
+fn to_lde_columns(
+    polys: &[CirclePoly<SimdBackend>],
+    config: &PcsConfig,
+    twiddles: &TwiddleTree<SimdBackend>,
+) -> Vec<CircleEvaluation<SimdBackend, M31, BitReversedOrder>> {
+    polys
+        .iter()
+        .map(|poly| {
+            let lde_domain = CanonicCoset::new(poly.log_size() + config.fri_config.log_blowup_factor).circle_domain();
+            poly.evaluate_with_twiddles(lde_domain, twiddles)
+        })
+        .collect()
+}
+
+// Helper function committing each component's trace as its own tree, in order, through one
+// commitment scheme so the precomputed twiddles are shared. Each root is mixed into the channel
+// as it is committed, so the trees are committed sequentially; only the hashing inside a commit
+// is parallel. Returns the new roots.
+fn commit_batch(
+    commitment_scheme: &mut CommitmentSchemeProver<'_, SimdBackend, Blake2sMerkleChannel>,
+    traces: Vec<ColumnVec<CircleEvaluation<SimdBackend, M31, BitReversedOrder>>>,
+    channel: &mut Blake2sChannel,
+) -> Vec<String> {
+    let first_tree = commitment_scheme.roots().len();
+    for trace in traces {
+        let mut tree_builder = commitment_scheme.tree_builder();
+        tree_builder.extend_evals(trace);
+        tree_builder.commit(channel);
+    }
+    commitment_scheme.roots()[first_tree..].iter().map(|root| root.to_string()).collect()
+}
+
+// Helper function asserting that a twiddle tree covers the LDE domain of every column of a trace,
+// so committing against too-small twiddles panics with the sizes involved rather than failing
+// later inside the FFT. An LDE domain of log size n is evaluated with the twiddles of its half
+// coset, of log size n - 1, so the tree's root coset must be at least that large.
+fn assert_twiddles_cover(
+    trace: &ColumnVec<CircleEvaluation<SimdBackend, M31, BitReversedOrder>>,
+    twiddles: &TwiddleTree<SimdBackend>,
+    config: &PcsConfig,
+) {
+    let max_lde_log_size = twiddles.root_coset.log_size + 1;
+    for (i, evaluation) in trace.iter().enumerate() {
+        let log_size = evaluation.domain.log_size();
+        let lde_log_size = log_size + config.fri_config.log_blowup_factor;
+        assert!(
+            lde_log_size <= max_lde_log_size,
+            "column {} of log size {} has an LDE domain of log size {} (blowup {}), but the twiddles only cover LDE domains up to log size {}",
+            i,
+            log_size,
+            lde_log_size,
+            config.fri_config.log_blowup_factor,
+            max_lde_log_size
+        );
+    }
+}
+
+// Helper function committing a trace as one tree after checking it against the twiddles with
+// assert_twiddles_cover. Returns the new root.
+fn commit_checked(
+    commitment_scheme: &mut CommitmentSchemeProver<'_, SimdBackend, Blake2sMerkleChannel>,
+    trace: ColumnVec<CircleEvaluation<SimdBackend, M31, BitReversedOrder>>,
+    channel: &mut Blake2sChannel,
+    config: &PcsConfig,
+    twiddles: &TwiddleTree<SimdBackend>,
+) -> String {
+    assert_twiddles_cover(&trace, twiddles, config);
+    let mut tree_builder = commitment_scheme.tree_builder();
+    tree_builder.extend_evals(trace);
+    tree_builder.commit(channel);
+    commitment_scheme.roots().last().unwrap().to_string()
+}
+
+// Helper function committing a trace as one tree and also returning the tree's leaf layer, read
+// back from the committed Merkle tree rather than recomputed. Leaves hash whole rows across the
+// columns (of the largest size, in the committed bit-reversed order), so leaf i indexes row i of
+// every column at once; there are no separate per-column leaves. The committed root is unchanged.
+fn commit_with_leaves(
+    commitment_scheme: &mut CommitmentSchemeProver<'_, SimdBackend, Blake2sMerkleChannel>,
+    trace: ColumnVec<CircleEvaluation<SimdBackend, M31, BitReversedOrder>>,
+    channel: &mut Blake2sChannel,
+) -> (String, Vec<String>) {
+    let mut tree_builder = commitment_scheme.tree_builder();
+    tree_builder.extend_evals(trace);
+    tree_builder.commit(channel);
+    let root = commitment_scheme.roots().last().unwrap().to_string();
+    // MerkleProver stores its layers from the root down, so the leaf layer is the last one.
+    let leaves = commitment_scheme.trees.last().unwrap().commitment.layers.last().unwrap();
+    (root, leaves.iter().map(|leaf| leaf.to_string()).collect())
+}
+
+// Helper function proving and verifying a single component with the same commitment steps as
+// example 05. Returns the proof's commitment roots and whether verification succeeded.
+fn prove_and_verify<E: FrameworkEval>(
+    eval: E,
+    trace: ColumnVec<CircleEvaluation<SimdBackend, M31, BitReversedOrder>>,
+) -> (Vec<String>, bool) {
+    prove_and_verify_with::<E, Blake2sMerkleChannel, Blake2sMerkleChannel>(eval, trace)
+}
+
+// Same as prove_and_verify, but proving with merkle channel PMC and verifying with VMC. The two
+// only need to share a hasher, e.g. a logging wrapper on the prover side and the plain channel on
+// the verifier side.
+fn prove_and_verify_with<E, PMC, VMC>(
+    eval: E,
+    trace: ColumnVec<CircleEvaluation<SimdBackend, M31, BitReversedOrder>>,
+) -> (Vec<String>, bool)
+where
+    E: FrameworkEval,
+    PMC: MerkleChannel,
+    VMC: MerkleChannel<H = PMC::H>,
+    PMC::C: Default,
+    VMC::C: Default,
+    SimdBackend: BackendForChannel<PMC>,
+{
+    let (component, proof) = prove_component::<E, PMC>(eval, trace);
+    let roots = proof.commitments.iter().map(|root| root.to_string()).collect();
+    let verified = verify_component::<E, VMC>(&component, proof);
+
+    (roots, verified)
+}
+
+// Error returned by try_prove_component
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ProvingError {
+    // The component declares no trace columns, so there is nothing to commit or constrain
+    EmptyComponent,
+}
+
+impl std::fmt::Display for ProvingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProvingError::EmptyComponent => write!(f, "component has no trace columns"),
+        }
+    }
+}
+
+// Helper function committing a single component's trace with the same steps as example 05
+// (an empty preprocessed tree, the log size, then the trace tree) and proving it. A component
+// without trace columns is rejected up front with ProvingError::EmptyComponent: its composition
+// polynomial would be identically zero, which stwo's prover does not handle.
+fn try_prove_component<E, MC>(
+    eval: E,
+    trace: ColumnVec<CircleEvaluation<SimdBackend, M31, BitReversedOrder>>,
+) -> Result<(FrameworkComponent<E>, StarkProof<MC::H>), ProvingError>
+where
+    E: FrameworkEval,
+    MC: MerkleChannel,
+    MC::C: Default,
+    SimdBackend: BackendForChannel<MC>,
+{
+    let component = FrameworkComponent::new(&mut TraceLocationAllocator::default(), eval, SecureField::zero());
+    if tree_log_sizes(&component, 1).is_empty() {
+        return Err(ProvingError::EmptyComponent);
+    }
+
+    let config = PcsConfig::default();
+    let twiddles = SimdBackend::precompute_twiddles(
+        CanonicCoset::new(component.max_constraint_log_degree_bound() + config.fri_config.log_blowup_factor)
+            .circle_domain()
+            .half_coset,
+    );
+
+    let channel = &mut MC::C::default();
+    let mut commitment_scheme = CommitmentSchemeProver::<SimdBackend, MC>::new(config, &twiddles);
+    let mut tree_builder = commitment_scheme.tree_builder();
+    tree_builder.extend_evals(vec![]);
+    tree_builder.commit(channel);
+    channel.mix_u64(component.log_size() as u64);
+    let mut tree_builder = commitment_scheme.tree_builder();
+    tree_builder.extend_evals(trace);
+    tree_builder.commit(channel);
+
+    let proof = prove(&[&component], channel, commitment_scheme).unwrap();
+    Ok((component, proof))
+}
+
+// Same as try_prove_component, for components known to have trace columns
+fn prove_component<E, MC>(
+    eval: E,
+    trace: ColumnVec<CircleEvaluation<SimdBackend, M31, BitReversedOrder>>,
+) -> (FrameworkComponent<E>, StarkProof<MC::H>)
+where
+    E: FrameworkEval,
+    MC: MerkleChannel,
+    MC::C: Default,
+    SimdBackend: BackendForChannel<MC>,
+{
+    try_prove_component::<E, MC>(eval, trace).unwrap()
+}
+
+// Helper function building the example AIR's columns over one packed vector of rows: the standard
+// table's col_1 and col_2, and col_3 = col_1 * col_2 + col_1
+fn mul_add_columns(config: &TableConfig) -> [BaseColumn; 3] {
+    let (col_1, col_2) = create_standard_table(config);
+    let mut col_3 = BaseColumn::zeros(N_LANES);
+    for row in 0..N_LANES {
+        col_3.set(row, col_1.at(row) * col_2.at(row) + col_1.at(row));
+    }
+    [col_1, col_2, col_3]
+}
+
+// Helper function building the example AIR's trace from mul_add_columns
+fn mul_add_trace(config: &TableConfig) -> ColumnVec<CircleEvaluation<SimdBackend, M31, BitReversedOrder>> {
+    columns_to_trace(mul_add_columns(config).to_vec(), LOG_N_LANES)
+}
+
+// Helper function proving the example AIR over mul_add_trace with the Blake2s channel
+fn prove_mul_add(config: &TableConfig) -> (FrameworkComponent<MulAddEval>, StarkProof<Blake2sMerkleHasher>) {
+    prove_component::<_, Blake2sMerkleChannel>(MulAddEval { log_size: LOG_N_LANES }, mul_add_trace(config))
+}
+
+// Helper function recording a check's outcome as {"valid": true} or {"valid": false, "error": ..}
+fn check_result_to_json<E: std::fmt::Display>(result: Result<(), E>) -> Value {
+    match result {
+        Ok(()) => json!({ "valid": true }),
+        Err(error) => json!({ "valid": false, "error": error.to_string() }),
+    }
+}
+
+// Helper function returning the column log sizes of one of a component's trees. A component with
+// no columns in a tree has no entry for it at all, so that tree's sizes are empty rather than out
+// of bounds.
+fn tree_log_sizes<E: FrameworkEval>(component: &FrameworkComponent<E>, tree: usize) -> Vec<u32> {
+    component.trace_log_degree_bounds().get(tree).cloned().unwrap_or_default()
+}
+
+// Helper function replaying prove_component's commitments on the verifier side and verifying
+fn verify_component<E, MC>(component: &FrameworkComponent<E>, proof: StarkProof<MC::H>) -> bool
+where
+    E: FrameworkEval,
+    MC: MerkleChannel,
+    MC::C: Default,
+{
+    let (commitment_scheme, channel) =
+        preprocessed_verifier::<MC>(proof.commitments[0], &tree_log_sizes(component, 0), PcsConfig::default());
+    verify_with_scheme(component, channel, commitment_scheme, proof)
+}
+
+// Helper function setting up a verifier whose preprocessed tree is already committed. The
+// preprocessed commitment is the same for every proof of a component, so this part can be done
+// ahead of time and handed to verify_with_scheme.
+fn preprocessed_verifier<MC>(
+    preprocessed_root: <MC::H as MerkleHasher>::Hash,
+    preprocessed_log_sizes: &[u32],
+    config: PcsConfig,
+) -> (CommitmentSchemeVerifier<MC>, MC::C)
+where
+    MC: MerkleChannel,
+    MC::C: Default,
+{
+    let mut channel = MC::C::default();
+    let mut commitment_scheme = CommitmentSchemeVerifier::<MC>::new(config);
+    commitment_scheme.commit(preprocessed_root, preprocessed_log_sizes, &mut channel);
+    (commitment_scheme, channel)
+}
+
+// Helper function finishing verification on a verifier from preprocessed_verifier: commits the
+// trace tree the way prove_component does, then verifies
+fn verify_with_scheme<E, MC>(
+    component: &FrameworkComponent<E>,
+    mut channel: MC::C,
+    mut commitment_scheme: CommitmentSchemeVerifier<MC>,
+    proof: StarkProof<MC::H>,
+) -> bool
+where
+    E: FrameworkEval,
+    MC: MerkleChannel,
+{
+    // prove_component mixes the component's log size, which all trace columns share.
+    channel.mix_u64(component.log_size() as u64);
+    commitment_scheme.commit(proof.commitments[1], &tree_log_sizes(component, 1), &mut channel);
+    verify(&[component], &mut channel, &mut commitment_scheme, proof).is_ok()
+}
+
+// Helper function replaying verification of a single component up to the OOD check and returning
+// (recomputed, claimed): the composition value the verifier rebuilds from the sampled mask values,
+// and the one the proof claims. verify only reports OodsNotMatching; this shows the mismatch. The
+// claim is the composition tree's four sampled coordinates combined with from_partial_evals.
+fn verify_oods<E, MC>(
+    component: &FrameworkComponent<E>,
+    proof: &StarkProof<MC::H>,
+) -> Result<(SecureField, SecureField), VerificationError>
+where
+    E: FrameworkEval,
+    MC: MerkleChannel,
+    MC::C: Default,
+{
+    let (mut commitment_scheme, mut channel) =
+        preprocessed_verifier::<MC>(proof.commitments[0], &tree_log_sizes(component, 0), PcsConfig::default());
+    channel.mix_u64(component.log_size() as u64);
+    commitment_scheme.commit(proof.commitments[1], &tree_log_sizes(component, 1), &mut channel);
+
+    let random_coeff = channel.draw_felt();
+    let composition_log_degree_bound = component.max_constraint_log_degree_bound();
+    commitment_scheme.commit(
+        *proof.commitments.last().unwrap(),
+        &[composition_log_degree_bound; SECURE_EXTENSION_DEGREE],
+        &mut channel,
+    );
+    let oods_point = CirclePoint::<SecureField>::get_random_point(&mut channel);
+
+    let mut evaluation_accumulator = PointEvaluationAccumulator::new(random_coeff);
+    component.evaluate_constraint_quotients_at_point(oods_point, &proof.sampled_values, &mut evaluation_accumulator);
+    let recomputed = evaluation_accumulator.finalize();
+
+    let composition_values = proof
+        .sampled_values
+        .last()
+        .filter(|columns| columns.len() == SECURE_EXTENSION_DEGREE && columns.iter().all(|column| column.len() == 1))
+        .ok_or_else(|| {
+            VerificationError::InvalidStructure("composition tree must sample each coordinate once".to_string())
+        })?;
+    let claimed = SecureField::from_partial_evals(std::array::from_fn(|i| composition_values[i][0]));
+
+    Ok((recomputed, claimed))
+}
+
+// Cheap structural pre-check of a proof, run before any hashing: one commitment per committed
+// tree plus the composition tree, one sampled/queried/decommitment entry per commitment, no more
+// queried values than the config's queries allow, and as many FRI inner layers and last-layer
+// coefficients as the config implies. The composition polynomial bounds the FRI degree: the first
+// fold maps the circle to the line, each inner layer halves the degree, and folding stops at
+// log_last_layer_degree_bound.
+fn verify_structure<H: MerkleHasher>(
+    components: &[&dyn Component],
+    config: &PcsConfig,
+    n_trace_trees: usize,
+    proof: &StarkProof<H>,
+) -> Result<(), VerificationError> {
+    let n_commitments = n_trace_trees + 1;
+    if proof.commitments.len() != n_commitments {
+        return Err(VerificationError::InvalidStructure(format!(
+            "expected {} commitments, got {}",
+            n_commitments,
+            proof.commitments.len()
+        )));
+    }
+    for (name, len) in [
+        ("sampled values", proof.sampled_values.len()),
+        ("queried values", proof.queried_values.len()),
+        ("decommitments", proof.decommitments.len()),
+    ] {
+        if len != n_commitments {
+            return Err(VerificationError::InvalidStructure(format!(
+                "expected {} trees of {}, got {}",
+                n_commitments, name, len
+            )));
+        }
+    }
+
+    // Each tree opens its columns at the distinct query positions of their domains, at most
+    // n_queries rows, stored row by row with one value per column. With fold step 1, each FRI
+    // inner layer adds at most one sibling value per query.
+    let n_queries = config.fri_config.n_queries;
+    for (tree, (values, columns)) in proof.queried_values.iter().zip(proof.sampled_values.iter()).enumerate() {
+        if values.len() > columns.len() * n_queries {
+            return Err(VerificationError::InvalidStructure(format!(
+                "tree {} has {} queried values, more than {} columns at {} queries",
+                tree,
+                values.len(),
+                columns.len(),
+                n_queries
+            )));
+        }
+    }
+    for (i, layer) in proof.fri_proof.inner_layers.iter().enumerate() {
+        if layer.fri_witness.len() > n_queries {
+            return Err(VerificationError::InvalidStructure(format!(
+                "FRI inner layer {} has {} witness values, more than {} queries",
+                i,
+                layer.fri_witness.len(),
+                n_queries
+            )));
+        }
+    }
+
+    let composition_log_degree_bound = components
+        .iter()
+        .map(|component| component.max_constraint_log_degree_bound())
+        .max()
+        .ok_or_else(|| VerificationError::InvalidStructure("no components to check against".to_string()))?;
+    let n_inner_layers = composition_log_degree_bound
+        .checked_sub(1)
+        .and_then(|line_log_degree_bound| line_log_degree_bound.checked_sub(config.fri_config.log_last_layer_degree_bound))
+        .unwrap_or(0) as usize;
+    if proof.fri_proof.inner_layers.len() != n_inner_layers {
+        return Err(VerificationError::InvalidStructure(format!(
+            "expected {} FRI inner layers, got {}",
+            n_inner_layers,
+            proof.fri_proof.inner_layers.len()
+        )));
+    }
+    let last_layer_len = 1 << config.fri_config.log_last_layer_degree_bound;
+    if proof.fri_proof.last_layer_poly.len() != last_layer_len {
+        return Err(VerificationError::InvalidStructure(format!(
+            "expected {} last layer coefficients, got {}",
+            last_layer_len,
+            proof.fri_proof.last_layer_poly.len()
+        )));
+    }
+    Ok(())
+}
+
+// Error returned by proof_self_check
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ProofIntegrityError {
+    // A field element limb is not below P
+    NonCanonicalValue(String),
+    // Per-tree vectors disagree in length with the number of commitments
+    InconsistentLengths(String),
+}
+
+impl std::fmt::Display for ProofIntegrityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProofIntegrityError::NonCanonicalValue(location) => write!(f, "non-canonical field element in {}", location),
+            ProofIntegrityError::InconsistentLengths(details) => write!(f, "inconsistent lengths: {}", details),
+        }
+    }
+}
+
+// Helper function sanity-checking a proof on its own, e.g. before sending it: every tree has its
+// sampled values, queried values and decommitment, the query openings and FRI layers agree with
+// the proof's own query count, and every secure-field element in the sampled values, the FRI
+// witnesses and the last FRI layer is made of canonical M31 limbs. Unlike verify_structure it
+// needs neither the components nor the config.
+//
+// The query count comes from the composition tree, whose columns all live on the largest domain:
+// it opens one value per column for each distinct queried row. No tree opens more rows than that.
+// Each FRI inner layer folds pairs of siblings (fold step 1): a layer with q queried positions in
+// g pairs opens 2g - q sibling values as its witness and hands g = (q + witness) / 2 positions to
+// the next layer, so the number of queries halves, up to the witness, from layer to layer.
+fn proof_self_check<H: MerkleHasher>(proof: &StarkProof<H>) -> Result<(), ProofIntegrityError> {
+    let n_trees = proof.commitments.len();
+    for (name, len) in [
+        ("sampled values", proof.sampled_values.len()),
+        ("queried values", proof.queried_values.len()),
+        ("decommitments", proof.decommitments.len()),
+    ] {
+        if len != n_trees {
+            return Err(ProofIntegrityError::InconsistentLengths(format!(
+                "{} commitments but {} trees of {}",
+                n_trees, len, name
+            )));
+        }
+    }
+
+    let n_columns: Vec<usize> = proof.sampled_values.iter().map(|tree| tree.len()).collect();
+    let n_composition_columns = n_columns.last().copied().unwrap_or(0);
+    let n_composition_values = proof.queried_values.last().map_or(0, |values| values.len());
+    if n_composition_columns == 0 || n_composition_values % n_composition_columns != 0 {
+        return Err(ProofIntegrityError::InconsistentLengths(format!(
+            "{} composition values do not fill {} columns",
+            n_composition_values, n_composition_columns
+        )));
+    }
+    let n_queries = n_composition_values / n_composition_columns;
+    for (tree, (values, columns)) in proof.queried_values.iter().zip(&n_columns).enumerate() {
+        if values.len() > columns * n_queries {
+            return Err(ProofIntegrityError::InconsistentLengths(format!(
+                "tree {} opens {} values, more than {} columns at {} queries",
+                tree,
+                values.len(),
+                columns,
+                n_queries
+            )));
+        }
+    }
+    let mut max_layer_queries = n_queries;
+    for (i, layer) in proof.fri_proof.inner_layers.iter().enumerate() {
+        let n_witness = layer.fri_witness.len();
+        if n_witness > max_layer_queries {
+            return Err(ProofIntegrityError::InconsistentLengths(format!(
+                "FRI inner layer {} has {} witness values, more than its at most {} queries",
+                i, n_witness, max_layer_queries
+            )));
+        }
+        max_layer_queries = (max_layer_queries + n_witness) / 2;
+    }
+
+    let is_canonical = |value: &SecureField| value.to_m31_array().iter().all(|limb| limb.0 < P);
+    let sampled_ok = proof
+        .sampled_values
+        .iter()
+        .flatten()
+        .flatten()
+        .all(is_canonical);
+    if !sampled_ok {
+        return Err(ProofIntegrityError::NonCanonicalValue("sampled values".to_string()));
+    }
+    for (i, layer) in proof.fri_proof.inner_layers.iter().enumerate() {
+        if !layer.fri_witness.iter().all(is_canonical) {
+            return Err(ProofIntegrityError::NonCanonicalValue(format!("FRI inner layer {} witness", i)));
+        }
+    }
+    if !proof.fri_proof.last_layer_poly.iter().all(is_canonical) {
+        return Err(ProofIntegrityError::NonCanonicalValue("FRI last layer".to_string()));
+    }
+    Ok(())
+}
+
+// Helper function to convert M31 to JSON
+fn m31_to_json(value: M31) -> Value {
+    json!({
+        "value": value.0,
+        "type": "M31"
+    })
+}
+
+// Helper function to extract ALL column data comprehensively
+fn extract_complete_column_data(col: &BaseColumn) -> Value {
+    let all_values: Vec<u32> = (0..col.len())
+        .map(|i| col.at(i).0)
+        .collect();
+    
+    // Find non-zero positions for easier testing
+    let non_zero_positions: Vec<Value> = (0..col.len())
+        .filter_map(|i| {
+            let val = col.at(i).0;
+            if val != 0 {
+                Some(json!({
+                    "index": i,
+                    "value": val
+                }))
+            } else {
+                None
+            }
+        })
+        .collect();
+    
+    json!({
+        "length": col.len(),
+        "data_chunks": col.data.len(),
+        "all_values": all_values,
+        "non_zero_positions": non_zero_positions,
+        "zero_count": all_values.iter().filter(|&&x| x == 0).count(),
+        "non_zero_count": all_values.iter().filter(|&&x| x != 0).count()
+    })
+}
+
+// Helper function to flatten a column into raw u32 limbs, one per element (SIMD padding excluded)
+fn base_column_to_u32_vec(col: &BaseColumn) -> Vec<u32> {
+    col.to_cpu().iter().map(|v| v.0).collect()
+}
+
+// Helper function to rebuild a column from raw u32 limbs, rejecting limbs outside [0, P)
+fn base_column_from_u32_vec(limbs: &[u32]) -> Option<BaseColumn> {
+    if limbs.iter().any(|&limb| limb >= P) {
+        return None;
+    }
+    Some(limbs.iter().map(|&limb| M31::from_u32_unchecked(limb)).collect())
+}
+
+// Helper function iterating a column's packed lanes, N_LANES values at a time, for SIMD kernels.
+// The last vector is zero-padded past the column's length.
+fn base_column_packed_iter(col: &BaseColumn) -> impl Iterator<Item = &PackedM31> {
+    col.data.iter()
+}
+
+// Helper function iterating a column's packed lanes mutably. Writes to the padding lanes of the
+// last vector are not part of the column's values.
+fn base_column_packed_iter_mut(col: &mut BaseColumn) -> impl Iterator<Item = &mut PackedM31> {
+    col.data.iter_mut()
+}
+
+// Helper function taking the discrete log of a base circle point: the index k with
+// M31_CIRCLE_GEN * k == point. The circle group has order 2^31, so k is recovered one bit at a
+// time (Pohlig-Hellman): the remainder point - G * k, doubled down to order at most 2, is the
+// identity exactly when the next bit is clear. Returns None for points not on the circle.
+fn circle_point_index(point: CirclePoint<M31>) -> Option<CirclePointIndex> {
+    let mut index = 0usize;
+    for bit in 0..M31_CIRCLE_LOG_ORDER {
+        let remainder = point - CirclePointIndex(index).to_point();
+        if remainder.repeated_double(M31_CIRCLE_LOG_ORDER - 1 - bit) != CirclePoint::zero() {
+            index |= 1 << bit;
+        }
+    }
+    let index = CirclePointIndex(index);
+    (index.to_point() == point).then_some(index)
+}
+
+// Helper function locating a point in a domain by its coset position. The first half of the
+// domain is half_coset.initial_index + i * step_size and the second half is the negation of the
+// first, so the natural index follows from the point's circle index with no scan.
+fn domain_index(domain: &CircleDomain, point: CirclePoint<M31>) -> Option<usize> {
+    let order = 1usize << M31_CIRCLE_LOG_ORDER;
+    let index = circle_point_index(point)?.0;
+    let half_coset = domain.half_coset;
+    let position = |index: usize| {
+        let offset = (index + order - half_coset.initial_index.0) % order;
+        (offset % half_coset.step_size.0 == 0).then(|| offset / half_coset.step_size.0)
+    };
+    position(index).or_else(|| position((order - index) % order).map(|i| i + half_coset.size()))
+}
+
+// Helper function reading an evaluation at a domain point. Values are stored in bit-reversed
+// order, so the point's natural domain index is bit-reversed before indexing. Returns None for
+// points outside the domain.
+fn evaluation_at_point(
+    evaluation: &CircleEvaluation<SimdBackend, M31, BitReversedOrder>,
+    point: CirclePoint<M31>,
+) -> Option<M31> {
+    let index = domain_index(&evaluation.domain, point)?;
+    Some(evaluation.values.at(bit_reverse_index(index, evaluation.domain.log_size())))
+}
+
+// Helper function iterating an evaluation in natural domain order, yielding each natural index
+// with its domain point and the value there (read from the bit-reversed storage)
+fn enumerate_with_points(
+    evaluation: &CircleEvaluation<SimdBackend, M31, BitReversedOrder>,
+) -> impl Iterator<Item = (usize, CirclePoint<M31>, M31)> + '_ {
+    let log_size = evaluation.domain.log_size();
+    evaluation
+        .domain
+        .iter()
+        .enumerate()
+        .map(move |(i, point)| (i, point, evaluation.values.at(bit_reverse_index(i, log_size))))
+}
+
+// Helper function reading a trace row. Columns are written row by row and wrapped directly as
+// BitReversedOrder evaluations, so trace row r is values[r], which holds the value at the
+// domain point domain.at(bit_reverse_index(r, log_size)).
+fn evaluation_row<B: ColumnOps<M31>>(evaluation: &CircleEvaluation<B, M31, BitReversedOrder>, trace_row: usize) -> M31 {
+    evaluation.values.at(trace_row)
+}
+
+// Helper function reading a contiguous range of trace rows, e.g. a window for streaming
+// constraint evaluation. Rows follow evaluation_row, so the window is in the order the rows were
+// written; each row's domain point is given by trace_row_point. Works on any backend.
+fn evaluation_window<B: ColumnOps<M31>>(
+    evaluation: &CircleEvaluation<B, M31, BitReversedOrder>,
+    rows: std::ops::Range<usize>,
+) -> Vec<M31> {
+    assert!(rows.end <= evaluation.values.len(), "window exceeds the evaluation");
+    rows.map(|row| evaluation_row(evaluation, row)).collect()
+}
+
+// Helper function iterating the first `len` values of a coset sub-evaluation. CosetSubEvaluation
+// only supports indexing and does not store its length, so the caller passes the coset size;
+// indexing handles the wraparound.
+fn coset_sub_evaluation_iter<'a>(
+    sub_evaluation: &'a CosetSubEvaluation<'a, M31>,
+    len: usize,
+) -> impl Iterator<Item = M31> + 'a {
+    (0..len as isize).map(move |i| sub_evaluation[i])
+}
+
+// Helper function returning the domain point a trace row is evaluated at
+fn trace_row_point(domain: &CircleDomain, trace_row: usize) -> CirclePoint<M31> {
+    domain.at(bit_reverse_index(trace_row, domain.log_size()))
+}
+
+// Helper function collecting the values a row-local constraint reads at a trace row, one per column
+fn trace_row_values(trace: &ColumnVec<CircleEvaluation<SimdBackend, M31, BitReversedOrder>>, trace_row: usize) -> Vec<M31> {
+    trace.iter().map(|evaluation| evaluation_row(evaluation, trace_row)).collect()
+}
+
+// Helper function zero-padding an evaluation to a larger domain, row by row: trace row r keeps its
+// value and the added rows are zero. The smaller canonic domain is not a subset of the larger
+// one, so this pads the trace, not the polynomial; use an LDE to extend the polynomial instead.
+fn pad_evaluation(
+    evaluation: &CircleEvaluation<SimdBackend, M31, BitReversedOrder>,
+    target_log_size: u32,
+) -> CircleEvaluation<SimdBackend, M31, BitReversedOrder> {
+    assert!(target_log_size >= evaluation.domain.log_size(), "cannot pad to a smaller domain");
+    let mut values = BaseColumn::zeros(1 << target_log_size);
+    for row in 0..evaluation.values.len() {
+        values.set(row, evaluation_row(evaluation, row));
+    }
+    CircleEvaluation::new(CanonicCoset::new(target_log_size).circle_domain(), values)
+}
+
+// Helper function to extract ALL trace polynomial data comprehensively
+fn extract_complete_trace_data(trace: &ColumnVec<CircleEvaluation<SimdBackend, M31, BitReversedOrder>>) -> Value {
+    let polynomials: Vec<Value> = trace
+        .iter()
+        .enumerate()
+        .map(|(i, evaluation)| evaluation_to_generator_json(i, evaluation))
+        .collect();
+    
+    json!({
+        "polynomial_count": trace.len(),
+        "polynomials": polynomials,
+        "data_integrity": {
+            "consistent_domain": trace.iter().all(|t| t.domain.log_size() == trace[0].domain.log_size()),
+            "consistent_length": trace.iter().all(|t| t.values.len() == trace[0].values.len())
+        }
+    })
+}
+
+// Helper function producing the JSON shape the generator emits for one trace polynomial, on any
+// backend (the shape only depends on the values in storage order)
+fn evaluation_to_generator_json<B: ColumnOps<M31>>(
+    polynomial_index: usize,
+    evaluation: &CircleEvaluation<B, M31, BitReversedOrder>,
+) -> Value {
+    let all_values: Vec<u32> = (0..evaluation.values.len()).map(|j| evaluation.values.at(j).0).collect();
+    
+    // Find non-zero positions
+    let non_zero_positions: Vec<Value> = (0..evaluation.values.len())
+        .filter_map(|j| {
+            let val = evaluation.values.at(j).0;
+            if val != 0 {
+                Some(json!({
+                    "index": j,
+                    "value": val
+                }))
+            } else {
+                None
+            }
+        })
+        .collect();
+    
+    json!({
+        "polynomial_index": polynomial_index,
+        "domain": {
+            "log_size": evaluation.domain.log_size(),
+            "size": evaluation.domain.size()
+        },
+        "values": {
+            "length": all_values.len(),
+            "all_values": all_values,
+            "non_zero_positions": non_zero_positions,
+            "zero_count": all_values.iter().filter(|&&x| x == 0).count(),
+            "non_zero_count": all_values.iter().filter(|&&x| x != 0).count(),
+            "first_value": all_values.first().copied().unwrap_or(0),
+            "second_value": all_values.get(1).copied().unwrap_or(0)
+        }
+    })
+}
+
+// Helper function rebuilding an evaluation on backend B from the generator's JSON shape for one
+// trace polynomial. Returns None if fields are missing, values are not canonical M31 limbs, or the
+// value count does not match the domain size.
+fn evaluation_from_generator_json<B: ColumnOps<M31>>(value: &Value) -> Option<CircleEvaluation<B, M31, BitReversedOrder>>
+where
+    Col<B, M31>: FromIterator<M31>,
+{
+    let log_size = u32::try_from(value["domain"]["log_size"].as_u64()?).ok()?;
+    let limbs = value["values"]["all_values"]
+        .as_array()?
+        .iter()
+        .map(|limb| limb.as_u64().and_then(|limb| u32::try_from(limb).ok()))
+        .collect::<Option<Vec<u32>>>()?;
+    if limbs.len() != 1 << log_size || limbs.iter().any(|&limb| limb >= P) {
+        return None;
+    }
+    let values = limbs.into_iter().map(M31::from_u32_unchecked).collect();
+    Some(CircleEvaluation::new(CanonicCoset::new(log_size).circle_domain(), values))
+}
+
+// Helper function to extract domain data comprehensively
+fn extract_complete_domain_data(domain: &stwo_prover::core::poly::circle::CircleDomain) -> Value {
+    json!({
+        "log_size": domain.log_size(),
+        "size": domain.size(),
+        "is_canonic": true, // CanonicCoset always creates canonic domains
+        "size_verification": {
+            "expected_size_from_log": 1_usize << domain.log_size(),
+            "actual_size": domain.size(),
+            "sizes_match": domain.size() == (1_usize << domain.log_size())
+        }
+    })
+}
+
+// Helper function returning the N_LANES consecutive natural-order domain points starting at
+// `vec_index * N_LANES`, packed lane by lane
+fn circle_domain_at_packed(domain: &CircleDomain, vec_index: usize) -> CirclePoint<PackedM31> {
+    let points: [CirclePoint<M31>; N_LANES] =
+        std::array::from_fn(|lane| domain.at(vec_index * N_LANES + lane));
+    CirclePoint {
+        x: PackedM31::from_array(points.map(|p| p.x)),
+        y: PackedM31::from_array(points.map(|p| p.y)),
+    }
+}
+
+// Helper function iterating a domain's points in bit-reversed order, one scalar point at a time.
+// Unlike the SIMD backend's CircleDomainBitRevIterator it has no minimum size, so domains smaller
+// than N_LANES can be iterated too.
+fn circle_domain_bit_rev_iter(domain: &CircleDomain) -> impl Iterator<Item = CirclePoint<M31>> + '_ {
+    let log_size = domain.log_size();
+    (0..domain.size()).map(move |i| domain.at(bit_reverse_index(i, log_size)))
+}
+
+// Packed domain points for every vector of a domain spanning several SIMD vectors
+fn generate_circle_domain_at_packed_vectors() -> Value {
+    let domain = CanonicCoset::new(LOG_N_LANES + 2).circle_domain();
+
+    let packed_points: Vec<Value> = (0..domain.size() / N_LANES)
+        .map(|vec_index| {
+            let packed = circle_domain_at_packed(&domain, vec_index);
+            let (xs, ys) = (packed.x.to_array(), packed.y.to_array());
+            let matches_at = (0..N_LANES).all(|lane| {
+                let point = domain.at(vec_index * N_LANES + lane);
+                point.x == xs[lane] && point.y == ys[lane]
+            });
+            json!({
+                "vec_index": vec_index,
+                "x": xs.iter().map(|v| v.0).collect::<Vec<u32>>(),
+                "y": ys.iter().map(|v| v.0).collect::<Vec<u32>>(),
+                "matches_at": matches_at
+            })
+        })
+        .collect();
+
+    json!({
+        "domain": extract_complete_domain_data(&domain),
+        "packed_points": packed_points
+    })
+}
+
+// Scalar bit-reversed iteration against domain.iter() followed by bit_reverse, including domains
+// smaller than a SIMD vector
+fn generate_circle_domain_bit_rev_iter_vectors() -> Value {
+    let cases: Vec<Value> = (1..=LOG_N_LANES + 2)
+        .map(|log_size| {
+            let domain = CanonicCoset::new(log_size).circle_domain();
+            let points: Vec<CirclePoint<M31>> = circle_domain_bit_rev_iter(&domain).collect();
+            let mut expected: Vec<CirclePoint<M31>> = domain.iter().collect();
+            bit_reverse(&mut expected);
+            // The SIMD iterator needs at least one full vector; unpacked lane by lane it must
+            // yield the same bit-reversed order
+            let matches_simd_iter = (log_size >= LOG_N_LANES).then(|| {
+                let simd_points: Vec<CirclePoint<M31>> = CircleDomainBitRevIterator::new(domain)
+                    .flat_map(|packed| {
+                        let (xs, ys) = (packed.x.to_array(), packed.y.to_array());
+                        (0..N_LANES).map(move |lane| CirclePoint { x: xs[lane], y: ys[lane] })
+                    })
+                    .collect();
+                simd_points == points
+            });
+            json!({
+                "log_size": log_size,
+                "x": points.iter().map(|p| p.x.0).collect::<Vec<u32>>(),
+                "y": points.iter().map(|p| p.y.0).collect::<Vec<u32>>(),
+                "matches_bit_reversed_iter": points == expected,
+                "matches_simd_bit_rev_iter": matches_simd_iter
+            })
+        })
+        .collect();
+
+    json!({
+        "cases": cases
+    })
+}
+
+// Round trip of a column that does not fill a whole number of SIMD vectors through raw limbs
+fn generate_base_column_u32_limb_vectors() -> Value {
+    let col: BaseColumn = (0..20).map(|i| M31::from(i * i + 1)).collect();
+    let limbs = base_column_to_u32_vec(&col);
+    let round_trip = base_column_from_u32_vec(&limbs).unwrap();
+
+    let out_of_range_limbs = [1, 2, P];
+
+    json!({
+        "column_length": col.len(),
+        "limbs": limbs,
+        "round_trip_length": round_trip.len(),
+        "round_trip_preserves_values": (0..col.len()).all(|i| col.at(i) == round_trip.at(i)),
+        "out_of_range_input": out_of_range_limbs,
+        "out_of_range_rejected": base_column_from_u32_vec(&out_of_range_limbs).is_none()
+    })
+}
+
+// Packed lane iteration over a column that does not fill a whole number of SIMD vectors
+fn generate_base_column_packed_iter_vectors() -> Value {
+    let mut col: BaseColumn = (0..20).map(|i| M31::from(i * i + 1)).collect();
+    let lanes: Vec<M31> = base_column_packed_iter(&col).flat_map(|packed| packed.to_array()).collect();
+    let (values, padding) = lanes.split_at(col.len());
+    let matches_at = values.iter().enumerate().all(|(i, &v)| v == col.at(i));
+
+    for packed in base_column_packed_iter_mut(&mut col) {
+        *packed = *packed + *packed;
+    }
+
+    json!({
+        "column_length": col.len(),
+        "n_packed": base_column_packed_iter(&col).count(),
+        "lanes": lanes.iter().map(|v| v.0).collect::<Vec<_>>(),
+        "matches_at": matches_at,
+        "padding_is_zero": padding.iter().all(|v| v.0 == 0),
+        "doubled_with_iter_mut": (0..col.len()).all(|i| col.at(i) == values[i] + values[i])
+    })
+}
+
+// Proves the example AIR combined with a squaring AIR as a single component
+fn generate_combined_eval_vectors(config: &TableConfig) -> Value {
+    let log_size = LOG_N_LANES;
+    let [col_1, col_2, col_3] = mul_add_columns(config);
+    let mut col_4 = BaseColumn::zeros(N_LANES);
+    for row in 0..N_LANES {
+        col_4.set(row, col_3.at(row) * col_3.at(row));
+    }
+    // SquareEval reads col_3 again as its input column, followed by col_4.
+    let trace = columns_to_trace(vec![col_1, col_2, col_3.clone(), col_3, col_4], log_size);
+
+    let n_constraints_separately = [
+        FrameworkComponent::new(&mut TraceLocationAllocator::default(), MulAddEval { log_size }, SecureField::zero()).n_constraints(),
+        FrameworkComponent::new(&mut TraceLocationAllocator::default(), SquareEval { log_size }, SecureField::zero()).n_constraints(),
+    ];
+    let combined = CombinedEval::new(MulAddEval { log_size }, SquareEval { log_size });
+    let combined_component = FrameworkComponent::new(&mut TraceLocationAllocator::default(), combined, SecureField::zero());
+    let n_constraints_combined = combined_component.n_constraints();
+
+    let (roots, verified) = prove_and_verify(CombinedEval::new(MulAddEval { log_size }, SquareEval { log_size }), trace);
+
+    json!({
+        "log_size": log_size,
+        "trace_columns": 5,
+        "n_constraints_separately": n_constraints_separately,
+        "n_constraints_combined": n_constraints_combined,
+        "n_constraints_is_sum": n_constraints_combined == n_constraints_separately.iter().sum::<usize>(),
+        "commitment_roots": roots,
+        "verification_successful": verified
+    })
+}
+
+// Reads the example trace at every domain point, plus points outside the domain
+fn generate_evaluation_at_point_vectors(config: &TableConfig) -> Value {
+    let (col_1, _) = create_standard_table(config);
+    let evaluation = &columns_to_trace(vec![col_1], LOG_N_LANES)[0];
+    let domain = evaluation.domain;
+
+    let on_domain: Vec<Value> = (0..domain.size())
+        .map(|i| {
+            let point = domain.at(i);
+            let value = evaluation_at_point(evaluation, point);
+            json!({
+                "natural_index": i,
+                "bit_reversed_index": bit_reverse_index(i, domain.log_size()),
+                "point": { "x": point.x.0, "y": point.y.0 },
+                "value": value.map(|v| v.0),
+                "index_from_point_matches": domain_index(&domain, point) == Some(i),
+                "matches_indexed_value": value == Some(evaluation.values.at(bit_reverse_index(i, domain.log_size())))
+            })
+        })
+        .collect();
+
+    let off_domain_points = [
+        CirclePoint::zero(),
+        CanonicCoset::new(LOG_N_LANES + 1).circle_domain().at(0),
+    ];
+    let off_domain: Vec<Value> = off_domain_points
+        .iter()
+        .map(|&point| {
+            json!({
+                "point": { "x": point.x.0, "y": point.y.0 },
+                "value": evaluation_at_point(evaluation, point).map(|v| v.0)
+            })
+        })
+        .collect();
+
+    json!({
+        "domain": extract_complete_domain_data(&domain),
+        "on_domain": on_domain,
+        "off_domain": off_domain
+    })
+}
+
+// Walks the example evaluation with its domain points in natural order
+fn generate_enumerate_with_points_vectors(config: &TableConfig) -> Value {
+    let (col_1, _) = create_standard_table(config);
+    let evaluation = &columns_to_trace(vec![col_1], LOG_N_LANES)[0];
+    let domain = evaluation.domain;
+
+    let entries: Vec<Value> = enumerate_with_points(evaluation)
+        .map(|(i, point, value)| {
+            json!({
+                "natural_index": i,
+                "point": { "x": point.x.0, "y": point.y.0 },
+                "value": value.0,
+                "point_matches_domain_at": point == domain.at(i),
+                "value_matches_at_point": evaluation_at_point(evaluation, point) == Some(value)
+            })
+        })
+        .collect();
+
+    json!({
+        "domain": extract_complete_domain_data(&domain),
+        "entries": entries
+    })
+}
+
+// Dumps the example trace row by row, as a failing constraint would see it
+fn generate_trace_row_vectors(config: &TableConfig) -> Value {
+    let (col_1, col_2) = create_standard_table(config);
+    let trace = columns_to_trace(vec![col_1, col_2], LOG_N_LANES);
+    let domain = trace[0].domain;
+
+    let rows: Vec<Value> = (0..domain.size())
+        .map(|row| {
+            let point = trace_row_point(&domain, row);
+            json!({
+                "trace_row": row,
+                "point": { "x": point.x.0, "y": point.y.0 },
+                "values": trace_row_values(&trace, row).iter().map(|v| v.0).collect::<Vec<u32>>()
+            })
+        })
+        .collect();
+
+    json!({
+        "column_count": trace.len(),
+        "rows": rows,
+        "row_0": trace_row_values(&trace, 0).iter().map(|v| v.0).collect::<Vec<u32>>(),
+        "row_1": trace_row_values(&trace, 1).iter().map(|v| v.0).collect::<Vec<u32>>()
+    })
+}
+
+// Commits the example trace through the commitment scheme and through reusable LDE columns
+fn generate_lde_columns_vectors(config: &TableConfig) -> Value {
+    let (col_1, col_2) = create_standard_table(config);
+    let trace = columns_to_trace(vec![col_1, col_2], LOG_N_LANES);
+    let config_pcs = PcsConfig::default();
+    let twiddles = SimdBackend::precompute_twiddles(
+        CanonicCoset::new(twiddle_domain_log_size(LOG_N_LANES, config_pcs.fri_config.log_blowup_factor))
+            .circle_domain()
+            .half_coset,
+    );
+
+    // Root through the commitment scheme
+    let channel = &mut Blake2sChannel::default();
+    let mut commitment_scheme = CommitmentSchemeProver::<SimdBackend, Blake2sMerkleChannel>::new(config_pcs, &twiddles);
+    let mut tree_builder = commitment_scheme.tree_builder();
+    tree_builder.extend_evals(trace.clone());
+    tree_builder.commit(channel);
+    let scheme_root = commitment_scheme.roots()[0];
+
+    // Root over the reusable LDE columns
+    let polys: Vec<CirclePoly<SimdBackend>> = trace
+        .into_iter()
+        .map(|evaluation| evaluation.interpolate_with_twiddles(&twiddles))
+        .collect();
+    let lde_columns = to_lde_columns(&polys, &config_pcs, &twiddles);
+    let merkle = MerkleProver::<SimdBackend, Blake2sMerkleHasher>::commit(
+        lde_columns.iter().map(|evaluation| &evaluation.values).collect(),
+    );
+
+    json!({
+        "trace_log_size": LOG_N_LANES,
+        "log_blowup_factor": config_pcs.fri_config.log_blowup_factor,
+        "lde_column_log_sizes": lde_columns.iter().map(|c| c.domain.log_size()).collect::<Vec<u32>>(),
+        "lde_column_lengths": lde_columns.iter().map(|c| c.values.len()).collect::<Vec<usize>>(),
+        "commitment_scheme_root": format!("{:x}", scheme_root),
+        "lde_columns_root": format!("{:x}", merkle.root()),
+        "roots_match": scheme_root == merkle.root()
+    })
+}
+
+// Checks that oversized twiddle domains are rejected with a descriptive message
+fn generate_twiddle_domain_log_size_vectors() -> Value {
+    let fri_log_blowup_factor = PcsConfig::default().fri_config.log_blowup_factor;
+    // With the default blowups, 28 rows give the largest valid log size 30 and 29 rows the boundary 31
+    let cases: Vec<Value> = [LOG_N_LANES, 28, 29, 30]
+        .into_iter()
+        .map(|log_num_rows| {
+            match catch_unwind_silent(|| twiddle_domain_log_size(log_num_rows, fri_log_blowup_factor)) {
+                Ok(log_size) => json!({
+                    "log_num_rows": log_num_rows,
+                    "valid": true,
+                    "log_size": log_size
+                }),
+                Err(payload) => json!({
+                    "log_num_rows": log_num_rows,
+                    "valid": false,
+                    "panic_message": panic_message(payload.as_ref())
+                }),
+            }
+        })
+        .collect();
+
+    json!({
+        "max_log_order": M31_CIRCLE_LOG_ORDER,
+        "constraint_eval_blowup_factor": CONSTRAINT_EVAL_BLOWUP_FACTOR,
+        "fri_log_blowup_factor": fri_log_blowup_factor,
+        "cases": cases
+    })
+}
+
+// Pads the example trace to larger domains
+fn generate_pad_evaluation_vectors(config: &TableConfig) -> Value {
+    let (col_1, col_2) = create_standard_table(config);
+    let trace = columns_to_trace(vec![col_1, col_2], LOG_N_LANES);
+    let original = &trace[0];
+    let original_points: Vec<CirclePoint<M31>> = original.domain.iter().collect();
+
+    let cases: Vec<Value> = [LOG_N_LANES, LOG_N_LANES + 1, LOG_N_LANES + 3]
+        .into_iter()
+        .map(|target_log_size| {
+            let padded = pad_evaluation(original, target_log_size);
+            let original_rows = original.values.len();
+            json!({
+                "target_log_size": target_log_size,
+                "padded_length": padded.values.len(),
+                "values": (0..padded.values.len()).map(|row| evaluation_row(&padded, row).0).collect::<Vec<u32>>(),
+                "original_rows_preserved": (0..original_rows).all(|row| evaluation_row(&padded, row) == evaluation_row(original, row)),
+                "added_rows_zero": (original_rows..padded.values.len()).all(|row| evaluation_row(&padded, row) == M31::zero()),
+                "shares_original_points": padded.domain.iter().any(|point| original_points.contains(&point))
+            })
+        })
+        .collect();
+
+    json!({
+        "original_log_size": LOG_N_LANES,
+        "cases": cases
+    })
+}
+
+// Proves the example AIR through the logging channel wrapper and verifies with the plain channel
+fn generate_logging_channel_vectors(config: &TableConfig) -> Value {
+    let log_size = LOG_N_LANES;
+    let trace = mul_add_trace(config);
+
+    let (plain_roots, plain_verified) = prove_and_verify(MulAddEval { log_size }, trace.clone());
+    let (logging_roots, logging_verified) =
+        prove_and_verify_with::<_, LoggingMerkleChannel<Blake2sMerkleChannel>, Blake2sMerkleChannel>(
+            MulAddEval { log_size },
+            trace,
+        );
+
+    json!({
+        "log_size": log_size,
+        "plain_commitment_roots": plain_roots,
+        "plain_verification_successful": plain_verified,
+        "logging_commitment_roots": logging_roots,
+        "logging_verification_successful": logging_verified,
+        "roots_match": plain_roots == logging_roots
+    })
 }
 
-impl Default for TableConfig {
-    fn default() -> Self {
-        Self {
-            col1_val0: 1,
-            col1_val1: 7,
-            col2_val0: 5,
-            col2_val1: 11,
-        }
+// Round-trips the example trace through the generator's JSON shape
+fn generate_generator_json_vectors(config: &TableConfig) -> Value {
+    let (col_1, col_2) = create_standard_table(config);
+    let trace = columns_to_trace(vec![col_1, col_2], LOG_N_LANES);
+
+    let round_trips: Vec<Value> = trace
+        .iter()
+        .enumerate()
+        .map(|(i, evaluation)| {
+            let encoded = evaluation_to_generator_json(i, evaluation);
+            let decoded = evaluation_from_generator_json::<SimdBackend>(&encoded);
+            json!({
+                "polynomial_index": i,
+                "decoded": decoded.is_some(),
+                "values_match": decoded.as_ref().is_some_and(|decoded| decoded.values.to_cpu() == evaluation.values.to_cpu()),
+                "domain_matches": decoded.as_ref().is_some_and(|decoded| decoded.domain == evaluation.domain),
+                "re_encoded_matches": decoded.is_some_and(|decoded| evaluation_to_generator_json(i, &decoded) == encoded)
+            })
+        })
+        .collect();
+
+    // The request's CpuBackend evaluation: same shape as the SIMD one, and round-trips on its own
+    let cpu_evaluation = CircleEvaluation::<CpuBackend, M31, BitReversedOrder>::new(trace[0].domain, trace[0].values.to_cpu());
+    let cpu_encoded = evaluation_to_generator_json(0, &cpu_evaluation);
+    let cpu_decoded = evaluation_from_generator_json::<CpuBackend>(&cpu_encoded);
+    let cpu_round_trip = json!({
+        "backend": "CpuBackend",
+        "matches_simd_encoding": cpu_encoded == evaluation_to_generator_json(0, &trace[0]),
+        "values_match": cpu_decoded.as_ref().is_some_and(|decoded| decoded.values == cpu_evaluation.values),
+        "domain_matches": cpu_decoded.as_ref().is_some_and(|decoded| decoded.domain == cpu_evaluation.domain)
+    });
+
+    let mut wrong_length = evaluation_to_generator_json(0, &trace[0]);
+    wrong_length["values"]["all_values"].as_array_mut().unwrap().pop();
+    let mut non_canonical = evaluation_to_generator_json(0, &trace[0]);
+    non_canonical["values"]["all_values"][0] = json!(P);
+
+    json!({
+        "round_trips": round_trips,
+        "cpu_round_trip": cpu_round_trip,
+        "wrong_length_rejected": evaluation_from_generator_json::<SimdBackend>(&wrong_length).is_none(),
+        "non_canonical_rejected": evaluation_from_generator_json::<SimdBackend>(&non_canonical).is_none()
+    })
+}
+
+// Builds cosets of valid and oversized log sizes
+fn generate_try_coset_vectors() -> Value {
+    let cases: Vec<Value> = [0u32, 5, M31_CIRCLE_LOG_ORDER, M31_CIRCLE_LOG_ORDER + 1]
+        .into_iter()
+        .map(|log_size| match try_coset(CirclePointIndex::generator(), log_size) {
+            Ok(coset) => json!({
+                "log_size": log_size,
+                "valid": true,
+                "size": coset.size()
+            }),
+            Err(error) => json!({
+                "log_size": log_size,
+                "valid": false,
+                "error": error.to_string()
+            }),
+        })
+        .collect();
+
+    json!({
+        "max_log_order": M31_CIRCLE_LOG_ORDER,
+        "cases": cases
+    })
+}
+
+// Domains with the same point set built three ways, and commitments of a column evaluated on each
+fn generate_canonicalize_domain_vectors(config: &TableConfig) -> Value {
+    let canonic = CanonicCoset::new(LOG_N_LANES).circle_domain();
+    let half_coset = canonic.half_coset;
+    let domains = [
+        ("canonic", canonic),
+        ("shifted", CircleDomain::new(half_coset.shift(half_coset.step_size))),
+        ("conjugate", CircleDomain::new(half_coset.conjugate())),
+    ];
+    let point_set = |domain: &CircleDomain| {
+        let mut points: Vec<(u32, u32)> = domain.iter().map(|point| (point.x.0, point.y.0)).collect();
+        points.sort_unstable();
+        points
+    };
+
+    // The same column, moved onto each domain through its polynomial, then committed
+    let (col_1, _) = create_standard_table(config);
+    let poly = CircleEvaluation::<SimdBackend, M31, BitReversedOrder>::new(canonic, col_1).interpolate();
+    let config_pcs = PcsConfig::default();
+    let twiddles = SimdBackend::precompute_twiddles(
+        CanonicCoset::new(twiddle_domain_log_size(LOG_N_LANES, config_pcs.fri_config.log_blowup_factor))
+            .circle_domain()
+            .half_coset,
+    );
+
+    let cases: Vec<Value> = domains
+        .iter()
+        .map(|(name, domain)| {
+            let domain_poly = poly.evaluate(*domain).interpolate();
+            let mut commitment_scheme = CommitmentSchemeProver::<SimdBackend, Blake2sMerkleChannel>::new(config_pcs, &twiddles);
+            let mut tree_builder = commitment_scheme.tree_builder();
+            tree_builder.extend_polys(vec![domain_poly.clone()]);
+            tree_builder.commit(&mut Blake2sChannel::default());
+            let canonical = canonicalize_domain(domain);
+            json!({
+                "construction": name,
+                "initial_index": domain.half_coset.initial_index.0,
+                "canonical_initial_index": canonical.half_coset.initial_index.0,
+                "same_point_set": point_set(domain) == point_set(&canonic),
+                "equals_canonic": *domain == canonic,
+                "canonicalizes_to_canonic": canonical == canonicalize_domain(&canonic),
+                "same_polynomial": domain_poly.coeffs.to_cpu() == poly.coeffs.to_cpu(),
+                "root": commitment_scheme.roots()[0].to_string()
+            })
+        })
+        .collect();
+
+    json!({
+        "log_size": LOG_N_LANES,
+        "cases": cases
+    })
+}
+
+// Commits two components' traces as a batch and one by one
+fn generate_commit_batch_vectors(config: &TableConfig) -> Value {
+    let [col_1, col_2, col_3] = mul_add_columns(config);
+    let mut col_4 = BaseColumn::zeros(N_LANES);
+    for row in 0..N_LANES {
+        col_4.set(row, col_3.at(row) * col_3.at(row));
+    }
+    let traces = vec![
+        columns_to_trace(vec![col_1, col_2, col_3.clone()], LOG_N_LANES),
+        columns_to_trace(vec![col_3, col_4], LOG_N_LANES),
+    ];
+    let config_pcs = PcsConfig::default();
+    let twiddles = SimdBackend::precompute_twiddles(
+        CanonicCoset::new(twiddle_domain_log_size(LOG_N_LANES, config_pcs.fri_config.log_blowup_factor))
+            .circle_domain()
+            .half_coset,
+    );
+
+    // Batch
+    let batch_channel = &mut Blake2sChannel::default();
+    let mut commitment_scheme = CommitmentSchemeProver::<SimdBackend, Blake2sMerkleChannel>::new(config_pcs, &twiddles);
+    let batch_roots = commit_batch(&mut commitment_scheme, traces.clone(), batch_channel);
+
+    // One by one
+    let sequential_channel = &mut Blake2sChannel::default();
+    let mut commitment_scheme = CommitmentSchemeProver::<SimdBackend, Blake2sMerkleChannel>::new(config_pcs, &twiddles);
+    for trace in traces {
+        let mut tree_builder = commitment_scheme.tree_builder();
+        tree_builder.extend_evals(trace);
+        tree_builder.commit(sequential_channel);
     }
+    let sequential_roots: Vec<String> = commitment_scheme.roots().iter().map(|root| root.to_string()).collect();
+
+    json!({
+        "n_components": 2,
+        "batch_roots": batch_roots,
+        "sequential_roots": sequential_roots,
+        "roots_match": batch_roots == sequential_roots,
+        "channel_digests_match": batch_channel.digest() == sequential_channel.digest()
+    })
 }
 
-// Helper function to create the standard table with configurable values
-fn create_standard_table(config: &TableConfig) -> (BaseColumn, BaseColumn) {
-    let num_rows = N_LANES;
-    
-    let mut col_1 = BaseColumn::zeros(num_rows);
-    col_1.set(0, M31::from(config.col1_val0));
-    col_1.set(1, M31::from(config.col1_val1));
+// Commits traces that fit the twiddles and one too large for them
+fn generate_commit_checked_vectors() -> Value {
+    let config_pcs = PcsConfig::default();
+    let twiddles = SimdBackend::precompute_twiddles(
+        CanonicCoset::new(twiddle_domain_log_size(LOG_N_LANES, config_pcs.fri_config.log_blowup_factor))
+            .circle_domain()
+            .half_coset,
+    );
 
-    let mut col_2 = BaseColumn::zeros(num_rows);
-    col_2.set(0, M31::from(config.col2_val0));
-    col_2.set(1, M31::from(config.col2_val1));
-    
-    (col_1, col_2)
+    let cases: Vec<Value> = [LOG_N_LANES, LOG_N_LANES + 3]
+        .into_iter()
+        .map(|log_size| {
+            let col: BaseColumn = (0..1 << log_size).map(|i| M31::from(i as u32)).collect();
+            let outcome = catch_unwind_silent(|| {
+                let mut commitment_scheme =
+                    CommitmentSchemeProver::<SimdBackend, Blake2sMerkleChannel>::new(config_pcs, &twiddles);
+                commit_checked(
+                    &mut commitment_scheme,
+                    columns_to_trace(vec![col], log_size),
+                    &mut Blake2sChannel::default(),
+                    &config_pcs,
+                    &twiddles,
+                )
+            });
+            match outcome {
+                Ok(root) => json!({
+                    "log_size": log_size,
+                    "committed": true,
+                    "root": root
+                }),
+                Err(payload) => json!({
+                    "log_size": log_size,
+                    "committed": false,
+                    "panic_message": panic_message(payload.as_ref())
+                }),
+            }
+        })
+        .collect();
+
+    json!({
+        "twiddle_root_coset_log_size": twiddles.root_coset.log_size,
+        "log_blowup_factor": config_pcs.fri_config.log_blowup_factor,
+        "cases": cases
+    })
 }
 
-// Helper function to convert M31 to JSON
-fn m31_to_json(value: M31) -> Value {
+// Runs the structural pre-check on a valid proof and on proofs with missing pieces
+fn generate_verify_structure_vectors(config: &TableConfig) -> Value {
+    let log_size = LOG_N_LANES;
+    let config_pcs = PcsConfig::default();
+    let (component, proof) = prove_mul_add(config);
+    let check =
+        |proof: &StarkProof<Blake2sMerkleHasher>| check_result_to_json(verify_structure(&[&component], &config_pcs, 2, proof));
+
+    let valid = check(&proof);
+    let mut missing_fri_layer = proof.clone();
+    missing_fri_layer.0.fri_proof.inner_layers.pop();
+    let mut missing_commitment = proof.clone();
+    missing_commitment.0.commitments.pop();
+    let mut extra_queried_values = proof.clone();
+    let max_trace_values = extra_queried_values.sampled_values[1].len() * config_pcs.fri_config.n_queries;
+    extra_queried_values.0.queried_values[1].resize(max_trace_values + 1, M31::zero());
+    let no_components = check_result_to_json(verify_structure(&[], &config_pcs, 2, &proof));
+
     json!({
-        "value": value.0,
-        "type": "M31"
+        "log_size": log_size,
+        "n_fri_inner_layers": proof.fri_proof.inner_layers.len(),
+        "valid_proof": valid,
+        "missing_fri_layer": check(&missing_fri_layer),
+        "missing_commitment": check(&missing_commitment),
+        "extra_queried_values": check(&extra_queried_values),
+        "no_components": no_components,
+        "full_verification_successful": verify_component::<_, Blake2sMerkleChannel>(&component, proof)
     })
 }
 
-// Helper function to extract ALL column data comprehensively
-fn extract_complete_column_data(col: &BaseColumn) -> Value {
-    let all_values: Vec<u32> = (0..col.len())
-        .map(|i| col.at(i).0)
+// Commits the example trace with and without reading back the leaf layer
+fn generate_commit_with_leaves_vectors(config: &TableConfig) -> Value {
+    let (col_1, col_2) = create_standard_table(config);
+    let trace = columns_to_trace(vec![col_1, col_2], LOG_N_LANES);
+    let config_pcs = PcsConfig::default();
+    let twiddles = SimdBackend::precompute_twiddles(
+        CanonicCoset::new(twiddle_domain_log_size(LOG_N_LANES, config_pcs.fri_config.log_blowup_factor))
+            .circle_domain()
+            .half_coset,
+    );
+
+    let mut commitment_scheme = CommitmentSchemeProver::<SimdBackend, Blake2sMerkleChannel>::new(config_pcs, &twiddles);
+    let (root, leaves) = commit_with_leaves(&mut commitment_scheme, trace.clone(), &mut Blake2sChannel::default());
+    // The first leaf hashes the first committed row across both columns
+    let committed = &commitment_scheme.trees.last().unwrap().evaluations;
+    let first_row: Vec<M31> = committed.iter().map(|column| column.values.at(0)).collect();
+    let first_leaf = Blake2sMerkleHasher::hash_node(None, &first_row).to_string();
+
+    let mut commitment_scheme = CommitmentSchemeProver::<SimdBackend, Blake2sMerkleChannel>::new(config_pcs, &twiddles);
+    let mut tree_builder = commitment_scheme.tree_builder();
+    tree_builder.extend_evals(trace);
+    tree_builder.commit(&mut Blake2sChannel::default());
+    let plain_root = commitment_scheme.roots()[0].to_string();
+
+    let committed_length = 1usize << (LOG_N_LANES + config_pcs.fri_config.log_blowup_factor);
+    json!({
+        // The request asked for per-column leaf vectors; a stwo tree has none to return
+        "leaf_layout": "row",
+        "deviation": "stwo Merkle leaves hash a whole row across all columns, so the committed tree's single leaf layer is returned instead of per-column leaf vectors",
+        "root": root,
+        "plain_root": plain_root,
+        "roots_match": root == plain_root,
+        "n_leaves": leaves.len(),
+        "n_leaves_matches_column_length": leaves.len() == committed_length,
+        "first_leaf": leaves[0],
+        "first_leaf_hashes_first_row": leaves[0] == first_leaf
+    })
+}
+
+// Verifies two proofs of the example AIR, over different tables, against one preprocessed setup
+// fixed ahead of time: the preprocessed root and log sizes are shared, and each proof gets a
+// verifier rebuilt from them by preprocessed_verifier, since verify_with_scheme consumes it
+fn generate_verify_with_scheme_vectors(config: &TableConfig) -> Value {
+    let log_size = LOG_N_LANES;
+    let other_config = TableConfig { col1_val0: 2, col1_val1: 3, col2_val0: 4, col2_val1: 5 };
+    let (component, proof) = prove_mul_add(config);
+    let (_, other_proof) = prove_mul_add(&other_config);
+
+    // Set up ahead of time, from the preprocessed commitment only
+    let preprocessed_root = proof.commitments[0];
+    let preprocessed_log_sizes = tree_log_sizes(&component, 0);
+    let verify_on_setup = |proof: StarkProof<Blake2sMerkleHasher>| {
+        let (commitment_scheme, channel) =
+            preprocessed_verifier::<Blake2sMerkleChannel>(preprocessed_root, &preprocessed_log_sizes, PcsConfig::default());
+        verify_with_scheme(&component, channel, commitment_scheme, proof)
+    };
+
+    let proofs = [("first", proof), ("second", other_proof)];
+    let results: Vec<Value> = proofs
+        .into_iter()
+        .map(|(name, proof)| {
+            let shares_preprocessed_root = proof.commitments[0] == preprocessed_root;
+            let from_scratch = verify_component::<_, Blake2sMerkleChannel>(&component, proof.clone());
+            let with_scheme = verify_on_setup(proof);
+            json!({
+                "proof": name,
+                "shares_preprocessed_root": shares_preprocessed_root,
+                "from_scratch_verified": from_scratch,
+                "with_scheme_verified": with_scheme,
+                "results_match": from_scratch == with_scheme
+            })
+        })
         .collect();
-    
-    // Find non-zero positions for easier testing
-    let non_zero_positions: Vec<Value> = (0..col.len())
-        .filter_map(|i| {
-            let val = col.at(i).0;
-            if val != 0 {
-                Some(json!({
-                    "index": i,
-                    "value": val
-                }))
-            } else {
-                None
-            }
+
+    json!({
+        "log_size": log_size,
+        "preprocessed_root": preprocessed_root.to_string(),
+        "preprocessed_log_sizes": preprocessed_log_sizes,
+        "proofs": results
+    })
+}
+
+// Proves the example AIR twice from the same inputs and fresh channels and compares the proofs.
+// StarkProof has no canonical byte encoding here, so proofs are compared through their Debug
+// form, which prints every commitment, sampled value, decommitment and FRI layer. Parallelism is
+// stwo's `parallel` feature, forwarded by this crate's `parallel` feature: run the generator with
+// and without `--features parallel` and diff the outputs to compare serial and parallel proofs.
+// The mode that ran is recorded.
+fn generate_prove_determinism_vectors(config: &TableConfig) -> Value {
+    let log_size = LOG_N_LANES;
+    let first = prove_mul_add(config).1;
+    let second = prove_mul_add(config).1;
+
+    json!({
+        "log_size": log_size,
+        "commitment_roots": first.commitments.iter().map(|root| root.to_string()).collect::<Vec<_>>(),
+        "parallel": cfg!(feature = "parallel"),
+        "commitments_identical": first.commitments == second.commitments,
+        "proofs_identical": format!("{:?}", first) == format!("{:?}", second)
+    })
+}
+
+// Proves a component with zero columns and zero constraints: try_prove_component rejects it with
+// ProvingError::EmptyComponent before anything is committed
+fn generate_empty_component_vectors() -> Value {
+    let log_size = LOG_N_LANES;
+    let result = try_prove_component::<_, Blake2sMerkleChannel>(EmptyEval { log_size }, vec![]);
+
+    json!({
+        "log_size": log_size,
+        "proved": result.is_ok(),
+        "rejected_as_empty": matches!(result, Err(ProvingError::EmptyComponent)),
+        "error": result.err().map(|error| error.to_string())
+    })
+}
+
+// Self-checks a fresh proof and tampered copies of it
+fn generate_proof_self_check_vectors(config: &TableConfig) -> Value {
+    let log_size = LOG_N_LANES;
+    let (_, proof) = prove_mul_add(config);
+    let check = |proof: &StarkProof<Blake2sMerkleHasher>| check_result_to_json(proof_self_check(proof));
+
+    // Length tampering uses canonical values, so only the consistency checks can catch it
+    let n_queries = proof.queried_values.last().unwrap().len() / proof.sampled_values.last().unwrap().len();
+    let mut tampered_fri_layer = proof.clone();
+    tampered_fri_layer.0.fri_proof.inner_layers[0].fri_witness.resize(n_queries + 1, SecureField::zero());
+    let mut tampered_openings = proof.clone();
+    let max_trace_values = tampered_openings.sampled_values[1].len() * n_queries;
+    tampered_openings.0.queried_values[1].resize(max_trace_values + 1, M31::zero());
+    let non_canonical = SecureField::from_m31_array([M31::from_u32_unchecked(P), M31::zero(), M31::zero(), M31::zero()]);
+    let mut non_canonical_sample = proof.clone();
+    non_canonical_sample.0.sampled_values[1][0][0] = non_canonical;
+    let mut missing_decommitment = proof.clone();
+    missing_decommitment.0.decommitments.pop();
+
+    json!({
+        "log_size": log_size,
+        "n_queries": n_queries,
+        "fresh_proof": check(&proof),
+        "tampered_fri_layer": check(&tampered_fri_layer),
+        "tampered_openings": check(&tampered_openings),
+        "non_canonical_sampled_value": check(&non_canonical_sample),
+        "missing_decommitment": check(&missing_decommitment)
+    })
+}
+
+// Compares CPU and SIMD twiddle precomputation on the same coset
+fn generate_twiddle_parity_vectors() -> Value {
+    let log_size = 10;
+    let coset = CanonicCoset::new(log_size).circle_domain().half_coset;
+    let cpu_twiddles = CpuBackend::precompute_twiddles(coset);
+    let simd_twiddles = SimdBackend::precompute_twiddles(coset);
+
+    // The layouts differ: the CPU backend stores each layer's x-coordinates as M31s, padded with
+    // one, while the SIMD backend stores them doubled (2x as a raw u32 below 2P, which its
+    // butterflies expect), padded with 2^31. Compare like with like: double the CPU values and
+    // drop the padding entry on both sides.
+    let doubled_without_padding = |twiddles: &[M31]| -> Vec<u32> {
+        twiddles[..twiddles.len() - 1].iter().map(|twiddle| 2 * twiddle.0).collect()
+    };
+    let without_padding = |twiddles: &[u32]| twiddles[..twiddles.len() - 1].to_vec();
+    let cpu_forward = doubled_without_padding(&cpu_twiddles.twiddles);
+    let cpu_inverse = doubled_without_padding(&cpu_twiddles.itwiddles);
+    let forward_identical = cpu_forward == without_padding(&simd_twiddles.twiddles);
+    let inverse_identical = cpu_inverse == without_padding(&simd_twiddles.itwiddles);
+    assert!(forward_identical && inverse_identical, "CPU and SIMD twiddles disagree after doubling");
+
+    json!({
+        "log_size": log_size,
+        "cpu_forward_count": cpu_twiddles.twiddles.len(),
+        "simd_forward_count": simd_twiddles.twiddles.len(),
+        "layout": "simd = 2 * cpu as raw u32, padding entry excluded",
+        "forward_identical": forward_identical,
+        "inverse_identical": inverse_identical,
+        "cpu_padding": cpu_twiddles.twiddles.last().unwrap().0,
+        "simd_padding": *simd_twiddles.twiddles.last().unwrap(),
+        "root_cosets_match": cpu_twiddles.root_coset == simd_twiddles.root_coset,
+        "first_few_twiddles": cpu_twiddles.twiddles.iter().take(8).map(|twiddle| twiddle.0).collect::<Vec<_>>()
+    })
+}
+
+// Reads windows of trace rows from the example column
+fn generate_evaluation_window_vectors(config: &TableConfig) -> Value {
+    let (col_1, _) = create_standard_table(config);
+    let evaluation = &columns_to_trace(vec![col_1], LOG_N_LANES)[0];
+
+    let windows: Vec<Value> = [0..2, 1..4, 0..N_LANES, 5..5]
+        .into_iter()
+        .map(|rows| {
+            let points: Vec<Value> = rows
+                .clone()
+                .map(|row| {
+                    let point = trace_row_point(&evaluation.domain, row);
+                    json!({ "x": point.x.0, "y": point.y.0 })
+                })
+                .collect();
+            json!({
+                "start": rows.start,
+                "end": rows.end,
+                "values": evaluation_window(evaluation, rows).iter().map(|value| value.0).collect::<Vec<u32>>(),
+                "points": points
+            })
         })
         .collect();
-    
+
+    // The request's CpuBackend evaluation gives the same windows
+    let cpu_evaluation = CircleEvaluation::<CpuBackend, M31, BitReversedOrder>::new(evaluation.domain, evaluation.values.to_cpu());
+    let expected_first_two = vec![M31::from(config.col1_val0), M31::from(config.col1_val1)];
+
     json!({
-        "length": col.len(),
-        "data_chunks": col.data.len(),
-        "all_values": all_values,
-        "non_zero_positions": non_zero_positions,
-        "zero_count": all_values.iter().filter(|&&x| x == 0).count(),
-        "non_zero_count": all_values.iter().filter(|&&x| x != 0).count()
+        "expected_first_two": [config.col1_val0, config.col1_val1],
+        "first_two_match": evaluation_window(evaluation, 0..2) == expected_first_two,
+        "cpu_first_two_match": evaluation_window(&cpu_evaluation, 0..2) == expected_first_two,
+        "cpu_windows_match": [0..2, 1..4, 0..N_LANES, 5..5]
+            .into_iter()
+            .all(|rows| evaluation_window(&cpu_evaluation, rows.clone()) == evaluation_window(evaluation, rows)),
+        "windows": windows
     })
 }
 
-// Helper function to extract ALL trace polynomial data comprehensively
-fn extract_complete_trace_data(trace: &ColumnVec<CircleEvaluation<SimdBackend, M31, BitReversedOrder>>) -> Value {
-    let polynomials: Vec<Value> = trace.iter().enumerate().map(|(i, evaluation)| {
-        let all_values: Vec<u32> = (0..evaluation.values.len()).map(|j| evaluation.values.at(j).0).collect();
-        
-        // Find non-zero positions
-        let non_zero_positions: Vec<Value> = (0..evaluation.values.len())
-            .filter_map(|j| {
-                let val = evaluation.values.at(j).0;
-                if val != 0 {
-                    Some(json!({
-                        "index": j,
-                        "value": val
-                    }))
-                } else {
-                    None
-                }
+// Checks iterating a coset sub-evaluation against index arithmetic on a log size 4 column
+fn generate_coset_sub_evaluation_iter_vectors() -> Value {
+    let log_size = 4;
+    let len = 1usize << log_size;
+    let values: Vec<M31> = (0..len as u32).map(|i| M31::from(i * i + 1)).collect();
+
+    let cases: Vec<Value> = [(0usize, 1isize), (3, 5), (7, -1), (2, -3)]
+        .into_iter()
+        .map(|(offset, step)| {
+            let sub_evaluation = CosetSubEvaluation::new(&values, offset, step);
+            let iterated: Vec<M31> = coset_sub_evaluation_iter(&sub_evaluation, len).collect();
+            let indexed: Vec<M31> = (0..len as isize)
+                .map(|i| values[(offset as isize + i * step).rem_euclid(len as isize) as usize])
+                .collect();
+            json!({
+                "offset": offset,
+                "step": step,
+                "values": iterated.iter().map(|value| value.0).collect::<Vec<u32>>(),
+                "matches_indexed": iterated == indexed
             })
-            .collect();
-        
-        json!({
-            "polynomial_index": i,
-            "domain": {
-                "log_size": evaluation.domain.log_size(),
-                "size": evaluation.domain.size()
-            },
-            "values": {
-                "length": all_values.len(),
-                "all_values": all_values,
-                "non_zero_positions": non_zero_positions,
-                "zero_count": all_values.iter().filter(|&&x| x == 0).count(),
-                "non_zero_count": all_values.iter().filter(|&&x| x != 0).count(),
-                "first_value": all_values.first().copied().unwrap_or(0),
-                "second_value": all_values.get(1).copied().unwrap_or(0)
-            }
         })
-    }).collect();
-    
+        .collect();
+
     json!({
-        "polynomial_count": trace.len(),
-        "polynomials": polynomials,
-        "data_integrity": {
-            "consistent_domain": trace.iter().all(|t| t.domain.log_size() == trace[0].domain.log_size()),
-            "consistent_length": trace.iter().all(|t| t.values.len() == trace[0].values.len())
-        }
+        "log_size": log_size,
+        "evaluation": values.iter().map(|value| value.0).collect::<Vec<u32>>(),
+        "cases": cases
     })
 }
 
-// Helper function to extract domain data comprehensively
-fn extract_complete_domain_data(domain: &stwo_prover::core::poly::circle::CircleDomain) -> Value {
+// Interpolates the example trace and evaluates it back on its own domain
+fn generate_evaluate_same_size_vectors(config: &TableConfig) -> Value {
+    let (col_1, col_2) = create_standard_table(config);
+    let trace = columns_to_trace(vec![col_1, col_2], LOG_N_LANES);
+    let twiddles = SimdBackend::precompute_twiddles(CanonicCoset::new(LOG_N_LANES).circle_domain().half_coset);
+
+    let columns: Vec<Value> = trace
+        .iter()
+        .enumerate()
+        .map(|(i, evaluation)| {
+            let poly = evaluation.clone().interpolate_with_twiddles(&twiddles);
+            let reevaluated = evaluate_same_size(&poly, evaluation.domain, &twiddles);
+            json!({
+                "column_index": i,
+                "coefficient_count": 1usize << poly.log_size(),
+                "domain_size": evaluation.domain.size(),
+                "reproduces_original": reevaluated.values.to_cpu() == evaluation.values.to_cpu()
+            })
+        })
+        .collect();
+
     json!({
-        "log_size": domain.log_size(),
-        "size": domain.size(),
-        "is_canonic": true, // CanonicCoset always creates canonic domains
-        "size_verification": {
-            "expected_size_from_log": 1_usize << domain.log_size(),
-            "actual_size": domain.size(),
-            "sizes_match": domain.size() == (1_usize << domain.log_size())
-        }
+        "log_size": LOG_N_LANES,
+        "columns": columns
+    })
+}
+
+// Evaluates the example trace at random out-of-domain points through the shortcut
+fn generate_evaluation_eval_at_point_vectors(config: &TableConfig) -> Value {
+    let (col_1, col_2) = create_standard_table(config);
+    let trace = columns_to_trace(vec![col_1, col_2], LOG_N_LANES);
+    let twiddles = SimdBackend::precompute_twiddles(CanonicCoset::new(LOG_N_LANES).circle_domain().half_coset);
+    let secure_json = |value: SecureField| json!(value.to_m31_array().map(|limb| limb.0));
+
+    let mut channel = Blake2sChannel::default();
+    let cases: Vec<Value> = (0..4)
+        .map(|_| {
+            let point = CirclePoint::<SecureField>::get_random_point(&mut channel);
+            let columns: Vec<Value> = trace
+                .iter()
+                .map(|evaluation| {
+                    let value = evaluation_eval_at_point(evaluation, point, &twiddles);
+                    json!({
+                        "value": secure_json(value),
+                        "matches_interpolate": value == evaluation.clone().interpolate().eval_at_point(point)
+                    })
+                })
+                .collect();
+            json!({
+                "point": { "x": secure_json(point.x), "y": secure_json(point.y) },
+                "columns": columns
+            })
+        })
+        .collect();
+
+    json!({
+        "log_size": LOG_N_LANES,
+        "cases": cases
+    })
+}
+
+// CirclePoint<F> already implements Add, Sub and Neg. The group law is
+// (x1, y1) + (x2, y2) = (x1x2 - y1y2, x1y2 + x2y1), with identity (1, 0) and -(x, y) = (x, -y).
+// Pins the operators on M31 points.
+fn generate_circle_point_ops_vectors() -> Value {
+    let point_json = |point: CirclePoint<M31>| json!({ "x": point.x.0, "y": point.y.0 });
+    let points = [
+        M31_CIRCLE_GEN,
+        M31_CIRCLE_GEN.double(),
+        CanonicCoset::new(4).circle_domain().at(3),
+        CirclePoint::zero(),
+    ];
+
+    let cases: Vec<Value> = points
+        .iter()
+        .flat_map(|&p| points.iter().map(move |&q| (p, q)))
+        .map(|(p, q)| {
+            let group_law = CirclePoint { x: p.x * q.x - p.y * q.y, y: p.x * q.y + q.x * p.y };
+            json!({
+                "p": point_json(p),
+                "q": point_json(q),
+                "sum": point_json(p + q),
+                "difference": point_json(p - q),
+                "sum_matches_group_law": p + q == group_law,
+                "commutative": p + q == q + p,
+                "difference_matches_negation": p - q == p + (-q),
+                "p_plus_neg_p_is_identity": p + (-p) == CirclePoint::zero()
+            })
+        })
+        .collect();
+
+    json!({
+        "identity": point_json(CirclePoint::zero()),
+        "cases": cases
+    })
+}
+
+// Proves and verifies the example AIR end to end with the Poseidon252 channel
+fn generate_poseidon252_prove_verify_vectors(config: &TableConfig) -> Value {
+    let log_size = LOG_N_LANES;
+    let trace = mul_add_trace(config);
+
+    let (roots, verified) =
+        prove_and_verify_with::<_, Poseidon252MerkleChannel, Poseidon252MerkleChannel>(MulAddEval { log_size }, trace);
+
+    json!({
+        "log_size": log_size,
+        "commitment_roots": roots,
+        "verification_successful": verified
+    })
+}
+
+// Audits the default PCS security level and configs tuned to a target level
+fn generate_pcs_security_vectors() -> Value {
+    let default = PcsConfig::default();
+    let targets: Vec<Value> = [1u32, 8, 20, 50, 96, 128]
+        .into_iter()
+        .map(|target_bits| {
+            let config = pcs_config_for_security_bits(target_bits);
+            json!({
+                "target_bits": target_bits,
+                "pow_bits": config.pow_bits,
+                "log_blowup_factor": config.fri_config.log_blowup_factor,
+                "n_queries": config.fri_config.n_queries,
+                "security_bits": config.security_bits(),
+                "meets_target": config.security_bits() >= target_bits
+            })
+        })
+        .collect();
+
+    json!({
+        "default": {
+            "pow_bits": default.pow_bits,
+            "log_blowup_factor": default.fri_config.log_blowup_factor,
+            "log_last_layer_degree_bound": default.fri_config.log_last_layer_degree_bound,
+            "n_queries": default.fri_config.n_queries,
+            "security_bits": default.security_bits(),
+            "expected_security_bits": DEFAULT_PCS_SECURITY_BITS,
+            "matches_expected": default.security_bits() == DEFAULT_PCS_SECURITY_BITS
+        },
+        "targets": targets
+    })
+}
+
+// Checks the trace! macro against the hand-built example table
+fn generate_trace_macro_vectors(config: &TableConfig) -> Value {
+    let (col_1, col_2) = create_standard_table(config);
+    let by_hand = columns_to_trace(vec![col_1, col_2], LOG_N_LANES);
+    let by_macro = trace!(log = LOG_N_LANES;
+        [config.col1_val0, config.col1_val1],
+        [config.col2_val0, config.col2_val1]
+    );
+    let columns_match = by_hand.len() == by_macro.len()
+        && by_hand.iter().zip(&by_macro).all(|(a, b)| a.domain == b.domain && a.values.to_cpu() == b.values.to_cpu());
+
+    json!({
+        "log_size": LOG_N_LANES,
+        "n_columns": by_macro.len(),
+        "columns_match": columns_match,
+        "trace": extract_complete_trace_data(&by_macro)
+    })
+}
+
+// Compares the recomputed and claimed OOD composition values for a valid and a tampered proof
+fn generate_verify_oods_vectors(config: &TableConfig) -> Value {
+    let log_size = LOG_N_LANES;
+    let (component, proof) = prove_mul_add(config);
+    let secure_json = |value: SecureField| json!(value.to_m31_array().map(|limb| limb.0));
+
+    // Shifting the first composition coordinate shifts the claim by the same amount.
+    let tampering = SecureField::from_u32_unchecked(1, 2, 3, 4);
+    let mut tampered = proof.clone();
+    tampered.0.sampled_values.last_mut().unwrap()[0][0] += tampering;
+
+    let case = |proof: &StarkProof<Blake2sMerkleHasher>| match verify_oods::<_, Blake2sMerkleChannel>(&component, proof) {
+        Ok((recomputed, claimed)) => json!({
+            "recomputed": secure_json(recomputed),
+            "claimed": secure_json(claimed),
+            "matches": recomputed == claimed,
+            "difference": secure_json(claimed - recomputed)
+        }),
+        Err(error) => json!({ "error": error.to_string() }),
+    };
+
+    json!({
+        "log_size": log_size,
+        "tampering": secure_json(tampering),
+        "valid_proof": case(&proof),
+        "tampered_proof": case(&tampered)
     })
 }
 
@@ -288,7 +2266,7 @@ fn generate_example_03_vectors(config: &TableConfig) -> Value {
     let config_pcs = PcsConfig::default();
 
     // Twiddle domain calculation (comprehensive breakdown)
-    let twiddle_domain_log_size = log_num_rows + CONSTRAINT_EVAL_BLOWUP_FACTOR + config_pcs.fri_config.log_blowup_factor;
+    let twiddle_domain_log_size = twiddle_domain_log_size(log_num_rows, config_pcs.fri_config.log_blowup_factor);
     let twiddle_domain_size = 1u32 << twiddle_domain_log_size;
 
     // ENHANCED: Actual twiddle computation
@@ -568,7 +2546,7 @@ fn generate_example_04_vectors(config: &TableConfig) -> Value {
     let config_pcs = PcsConfig::default();
 
     // Twiddle domain calculation
-    let twiddle_domain_log_size = log_num_rows + CONSTRAINT_EVAL_BLOWUP_FACTOR + config_pcs.fri_config.log_blowup_factor;
+    let twiddle_domain_log_size = twiddle_domain_log_size(log_num_rows, config_pcs.fri_config.log_blowup_factor);
     let twiddle_domain_size = 1u32 << twiddle_domain_log_size;
 
     // Actual twiddle computation
@@ -1070,7 +3048,7 @@ fn generate_example_05_vectors(config: &TableConfig) -> Value {
     let config_pcs = PcsConfig::default();
 
     // Twiddle domain calculation
-    let twiddle_domain_log_size = log_num_rows + CONSTRAINT_EVAL_BLOWUP_FACTOR + config_pcs.fri_config.log_blowup_factor;
+    let twiddle_domain_log_size = twiddle_domain_log_size(log_num_rows, config_pcs.fri_config.log_blowup_factor);
     let twiddle_domain_size = 1u32 << twiddle_domain_log_size;
 
     // Actual twiddle computation
@@ -1782,6 +3760,41 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             "04_constraints_over_trace_polynomial": generate_example_04_vectors(&config),
             "05_proving_an_air": generate_example_05_vectors(&config)
         },
+        "utilities": {
+            "circle_domain_at_packed": generate_circle_domain_at_packed_vectors(),
+            "circle_domain_bit_rev_iter": generate_circle_domain_bit_rev_iter_vectors(),
+            "base_column_u32_limbs": generate_base_column_u32_limb_vectors(),
+            "base_column_packed_iter": generate_base_column_packed_iter_vectors(),
+            "combined_eval": generate_combined_eval_vectors(&config),
+            "evaluation_at_point": generate_evaluation_at_point_vectors(&config),
+            "trace_rows": generate_trace_row_vectors(&config),
+            "enumerate_with_points": generate_enumerate_with_points_vectors(&config),
+            "lde_columns": generate_lde_columns_vectors(&config),
+            "twiddle_domain_log_size": generate_twiddle_domain_log_size_vectors(),
+            "pad_evaluation": generate_pad_evaluation_vectors(&config),
+            "logging_channel": generate_logging_channel_vectors(&config),
+            "generator_json": generate_generator_json_vectors(&config),
+            "try_coset": generate_try_coset_vectors(),
+            "canonicalize_domain": generate_canonicalize_domain_vectors(&config),
+            "commit_batch": generate_commit_batch_vectors(&config),
+            "commit_checked": generate_commit_checked_vectors(),
+            "verify_structure": generate_verify_structure_vectors(&config),
+            "commit_with_leaves": generate_commit_with_leaves_vectors(&config),
+            "verify_with_scheme": generate_verify_with_scheme_vectors(&config),
+            "prove_determinism": generate_prove_determinism_vectors(&config),
+            "empty_component": generate_empty_component_vectors(),
+            "proof_self_check": generate_proof_self_check_vectors(&config),
+            "twiddle_parity": generate_twiddle_parity_vectors(),
+            "evaluation_window": generate_evaluation_window_vectors(&config),
+            "evaluate_same_size": generate_evaluate_same_size_vectors(&config),
+            "circle_point_ops": generate_circle_point_ops_vectors(),
+            "poseidon252_prove_verify": generate_poseidon252_prove_verify_vectors(&config),
+            "pcs_security": generate_pcs_security_vectors(),
+            "trace_macro": generate_trace_macro_vectors(&config),
+            "coset_sub_evaluation_iter": generate_coset_sub_evaluation_iter_vectors(),
+            "evaluation_eval_at_point": generate_evaluation_eval_at_point_vectors(&config),
+            "verify_oods": generate_verify_oods_vectors(&config)
+        },
         "global_constants": {
             "N_LANES": N_LANES,
             "LOG_N_LANES": LOG_N_LANES